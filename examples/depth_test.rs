@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use bevy_prototype_debug_lines::{DebugLines, DebugLinesPlugin};
+use bevy_prototype_debug_lines::{DebugLines, DebugLinesPlugin, DebugLinesSettings};
 
 fn main() {
     App::new()
@@ -8,7 +8,7 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(DebugLinesPlugin::with_depth_test(true))
         .add_systems(Startup, setup)
-        .add_systems(Update, demo)
+        .add_systems(Update, (demo, toggle_depth_test))
         .run();
 }
 
@@ -38,3 +38,10 @@ fn demo(mut lines: ResMut<DebugLines>) {
         Color::RED,
     );
 }
+
+fn toggle_depth_test(keyboard_input: Res<Input<KeyCode>>, mut settings: ResMut<DebugLinesSettings>) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        settings.depth_test = !settings.depth_test;
+        info!("Toggled depth test: {}.", settings.depth_test);
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::{core_pipeline::clear_color::ClearColorConfig, prelude::*};
+
+use bevy_prototype_debug_lines::{DebugLines, DebugLinesPlugin};
+
+fn main() {
+    App::new()
+        .insert_resource(Msaa::default())
+        .add_plugins(DefaultPlugins)
+        .add_plugins(DebugLinesPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, demo)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 0.0, 5.0),
+        ..default()
+    });
+    // A 2d camera drawn on top of the 3d one.
+    commands.spawn(Camera2dBundle {
+        camera: Camera {
+            order: 1,
+            ..default()
+        },
+        camera_2d: Camera2d {
+            clear_color: ClearColorConfig::None,
+        },
+        ..default()
+    });
+}
+
+fn demo(time: Res<Time>, mut lines: ResMut<DebugLines>) {
+    let seconds = time.elapsed_seconds();
+
+    // Spinning line in the 3d world.
+    lines.in_3d().line_colored(
+        Vec3::new(-f32::cos(seconds), 0.0, -f32::sin(seconds)),
+        Vec3::new(f32::cos(seconds), 0.0, f32::sin(seconds)),
+        0.0,
+        Color::GREEN,
+    );
+
+    // Box around the screen center in the 2d overlay.
+    let mut overlay = lines.in_2d();
+    let corners = [
+        Vec3::new(-100.0, -100.0, 0.0),
+        Vec3::new(100.0, -100.0, 0.0),
+        Vec3::new(100.0, 100.0, 0.0),
+        Vec3::new(-100.0, 100.0, 0.0),
+    ];
+    for i in 0..corners.len() {
+        overlay.line_colored(corners[i], corners[(i + 1) % corners.len()], 0.0, Color::YELLOW);
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::{ecs::query::Has, prelude::*, render::primitives::Aabb, sprite::Mesh2dHandle};
+
+use crate::{DebugShapes, EntityVisibility, LineDimension};
+
+/// Draws the [`Aabb`] of the entity as a cuboid, following its [`GlobalTransform`].
+///
+/// The box is drawn for 3d cameras if the entity has a 3d mesh, and for 2d cameras if it has a 2d
+/// mesh or a sprite. Other entities use the default [`LineDimension`].
+///
+/// Bevy adds an [`Aabb`] to entities with a mesh or sprite automatically. The boxes can be
+/// toggled for all entities at once with [`ShowAabbSettings`].
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn spawn_crate(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+///     commands.spawn((
+///         PbrBundle {
+///             mesh: meshes.add(shape::Cube::default().into()),
+///             ..default()
+///         },
+///         ShowAabb::colored(Color::GREEN),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ShowAabb {
+    /// Color of the box, [`ShowAabbSettings::color`] if not set.
+    pub color: Option<Color>,
+}
+
+impl ShowAabb {
+    pub fn colored(color: Color) -> Self {
+        Self { color: Some(color) }
+    }
+}
+
+/// Settings for the boxes drawn for [`ShowAabb`] entities.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ShowAabbSettings {
+    /// Whether the boxes are drawn.
+    pub enabled: bool,
+    /// Color of the boxes which don't have their own.
+    pub color: Color,
+}
+
+impl Default for ShowAabbSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: Color::WHITE,
+        }
+    }
+}
+
+// The components telling the kind of camera an entity is drawn for.
+type DimensionComponents = (Has<Handle<Mesh>>, Has<Mesh2dHandle>, Has<Sprite>, Has<TextureAtlasSprite>);
+
+// Draws the bounding box of the entities with `ShowAabb`.
+pub(crate) fn draw_aabbs(
+    settings: Res<ShowAabbSettings>,
+    mut shapes: ResMut<DebugShapes>,
+    visibility: EntityVisibility,
+    entities: Query<(Entity, &ShowAabb, &Aabb, &GlobalTransform, DimensionComponents)>,
+) {
+    if !settings.enabled {
+        return;
+    }
+
+    let visible = entities.iter().filter(|(entity, ..)| visibility.is_visible(*entity));
+    for (_, show_aabb, aabb, transform, (mesh_3d, mesh_2d, sprite, atlas_sprite)) in visible {
+        let dimension = if mesh_3d {
+            LineDimension::ThreeD
+        } else if mesh_2d || sprite || atlas_sprite {
+            LineDimension::TwoD
+        } else {
+            LineDimension::default()
+        };
+
+        let (scale, rotation, _) = transform.to_scale_rotation_translation();
+        shapes
+            .cuboid()
+            .position(transform.transform_point(aabb.center.into()))
+            .size(Vec3::from(aabb.half_extents) * 2.0 * scale)
+            .rotation(rotation)
+            .color(show_aabb.color.unwrap_or(settings.color))
+            .dimension(dimension);
+    }
+}
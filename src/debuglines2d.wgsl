@@ -1,26 +1,171 @@
-#import bevy_sprite::mesh2d_view_bindings::view
+#import bevy_sprite::mesh2d_view_bindings::{view, globals}
 
 
 struct Vertex {
     @location(0) place: vec3<f32>,
-    @location(1) color: vec4<f32>
+    @location(1) color: vec4<f32>,
+#ifdef LINES_OTHER
+    // Other end of the line.
+    @location(2) other: vec3<f32>,
+#endif
+#ifdef LINES_THICK
+    // Line width, and the side of the line this vertex is on.
+    @location(3) params: vec2<f32>,
+#endif
+#ifdef LINES_DASHED
+    // 0 at the start of the line and 1 at its end, then the dash, gap and speed of the pattern.
+    @location(4) dash: vec4<f32>,
+#endif
+#ifdef LINES_ANCHORED
+    // Offset from the position the text or point is anchored at.
+    @location(5) offset: vec2<f32>,
+#endif
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) color: vec4<f32>
+    @location(0) color: vec4<f32>,
+#ifdef LINES_DASHED
+    // Distance from the start of the line. There is no perspective in 2d, so it is interpolated
+    // linearly on screen.
+    @location(1) dash_distance: f32,
+    @location(2) @interpolate(flat) dash_pattern: vec3<f32>,
+#endif
+#ifdef LINES_POINTS
+    // Position in the square around the point, and half its size, in pixels.
+    @location(3) marker: vec2<f32>,
+    @location(4) @interpolate(flat) marker_size: f32,
+#endif
 };
 
+// Position of a point of a line in clip space.
+fn to_clip(position: vec3<f32>) -> vec4<f32> {
+#ifdef LINES_SPACE_NDC
+    return vec4<f32>(position.xy, 0.5, 1.0);
+#else
+#ifdef LINES_SPACE_SCREEN
+    let ndc = 2.0 * position.xy / view.viewport.zw - 1.0;
+    return vec4<f32>(ndc.x, -ndc.y, 0.5, 1.0);
+#else
+    return view.view_proj * vec4<f32>(position, 1.0);
+#endif
+#endif
+}
+
+// Position of a point in clip space on the viewport, in pixels.
+fn to_screen(clip: vec4<f32>) -> vec2<f32> {
+    return view.viewport.zw * (0.5 * clip.xy / clip.w + 0.5);
+}
+
+#ifdef LINES_THICK
+// Expands the line into a quad, see debuglines.wgsl.
+fn thick_line(vertex: Vertex) -> vec4<f32> {
+    let clip = to_clip(vertex.place);
+    let clip_other = to_clip(vertex.other);
+
+    let resolution = view.viewport.zw;
+    let screen = to_screen(clip);
+    let screen_other = to_screen(clip_other);
+
+    var direction = vec2<f32>(1.0, 0.0);
+    if distance(screen, screen_other) > 0.0001 {
+        direction = normalize(screen_other - screen);
+    }
+    let normal = vec2<f32>(-direction.y, direction.x);
+
+    var width = vertex.params.x;
+#ifdef LINES_WORLD_WIDTH
+    // World units to pixels.
+    width = width * view.projection[1][1] * resolution.y * 0.5 / clip.w;
+#endif
+
+    let offset_screen = screen + normal * vertex.params.y * width * 0.5;
+    return vec4<f32>(clip.w * (2.0 * offset_screen / resolution - 1.0), clip.z, clip.w);
+}
+#endif
+
+#ifdef LINES_ANCHORED
+// Moves the vertex of a character or point marker away from the position it is anchored at, along
+// the axes of the camera in world units, or of the screen in pixels.
+fn anchored_vertex(vertex: Vertex) -> vec4<f32> {
+#ifdef LINES_ANCHORED_WORLD
+    let right = view.view[0].xyz;
+    let up = view.view[1].xyz;
+    return to_clip(vertex.place + right * vertex.offset.x + up * vertex.offset.y);
+#else
+    let clip = to_clip(vertex.place);
+    return vec4<f32>(clip.xy + 2.0 * vertex.offset / view.viewport.zw * clip.w, clip.zw);
+#endif
+}
+#endif
+
 @vertex
 fn vertex(vertex: Vertex) -> VertexOutput {
     var out: VertexOutput;
-    out.clip_position = view.view_proj * vec4<f32>(vertex.place, 1.0);
+#ifdef LINES_THICK
+    out.clip_position = thick_line(vertex);
+#else
+#ifdef LINES_ANCHORED
+    out.clip_position = anchored_vertex(vertex);
+#else
+    out.clip_position = to_clip(vertex.place);
+#endif
+#endif
     out.color = vertex.color;
+#ifdef LINES_POINTS
+    out.marker = vertex.offset;
+    out.marker_size = abs(vertex.offset.x);
+#endif
+#ifdef LINES_DASHED
+#ifdef LINES_DASH_PIXELS
+    let line_length = distance(to_screen(to_clip(vertex.place)), to_screen(to_clip(vertex.other)));
+#else
+    let line_length = distance(vertex.place, vertex.other);
+#endif
+    out.dash_distance = vertex.dash.x * line_length;
+    out.dash_pattern = vertex.dash.yzw;
+#endif
 
     return out;
 }
 
+#ifdef LINES_DASHED
+// Whether the point at `along` from the start of a line falls in a gap of its dash pattern.
+fn in_dash_gap(along: f32, pattern: vec3<f32>) -> bool {
+    let period = pattern.x + pattern.y;
+    let offset = along - globals.time * pattern.z;
+    return offset - floor(offset / period) * period > pattern.x;
+}
+#endif
+
+#ifdef LINES_POINTS
+// Whether the pixel at `marker` in the square around a point, of half size `size`, falls outside of
+// its marker.
+fn outside_marker(marker: vec2<f32>, size: f32) -> bool {
+#ifdef POINTS_CIRCLE
+    return length(marker) > size;
+#else
+#ifdef POINTS_CROSS
+    // Diagonals about 3 pixels wide.
+    return abs(marker.x - marker.y) > 2.0 && abs(marker.x + marker.y) > 2.0;
+#else
+    return false;
+#endif
+#endif
+}
+#endif
+
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+#ifdef LINES_DASHED
+    if in_dash_gap(in.dash_distance, in.dash_pattern) {
+        discard;
+    }
+#endif
+#ifdef LINES_POINTS
+    if outside_marker(in.marker, in.marker_size) {
+        discard;
+    }
+#endif
     return in.color;
 }
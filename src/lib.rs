@@ -4,90 +4,286 @@ use bevy::{
     pbr::{NotShadowCaster, NotShadowReceiver},
     prelude::*,
     render::{
+        extract_resource::{ExtractResource, ExtractResourcePlugin},
         Extract,
         mesh::{/*Indices,*/ Mesh, VertexAttributeValues},
-        Render,
-        render_phase::AddRenderCommand,
         render_resource::PrimitiveTopology,
-        render_resource::Shader, view::{NoFrustumCulling, RenderLayers},
+        render_resource::Shader, view::{NoFrustumCulling, RenderLayers, VisibilitySystems},
     },
+    sprite::Mesh2dHandle,
+    transform::TransformSystem,
+    utils::HashMap,
 };
 use bevy::render::mesh::MeshVertexAttribute;
 use bevy::render::render_resource::VertexFormat;
+use bevy::ecs::system::SystemParam;
 
 use shapes::AddLines;
 
 #[cfg(feature = "shapes")]
-pub use crate::shapes::DebugShapes;
+pub use crate::aabb::{ShowAabb, ShowAabbSettings};
+#[cfg(feature = "shapes")]
+pub use crate::shapes::{DebugShapes, ShowAxes, ShowGrid};
 
 #[cfg(feature = "shapes")]
 pub mod shapes;
 
+#[cfg(feature = "shapes")]
+mod aabb;
+mod local;
+mod normals;
 mod render_dim;
+mod slot_map;
+mod text;
+mod wireframe;
+
+pub use local::{DebugLinesLocal, LocalLine};
+pub use normals::ShowNormals;
+pub use text::TextSize;
+pub use wireframe::ShowWireframe;
+
+use slot_map::{SlotKey, SlotMap};
+
+// See debuglines.wgsl for explanation on 2 shaders.
+//pub(crate) const SHADER_FILE: &str = include_str!("debuglines.wgsl");
+pub(crate) const DEBUG_LINES_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(17477439189930443325);
+pub(crate) const DEBUG_LINES_2D_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(9305274123906714071);
+
+/// Settings for the debug lines which can be changed while the app is running.
+///
+/// Inserted by [`DebugLinesPlugin`] from its own settings.
+///
+/// # Usage
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// // Toggles "X-ray" lines, drawn through other geometry.
+/// fn toggle_xray(keys: Res<Input<KeyCode>>, mut settings: ResMut<DebugLinesSettings>) {
+///     if keys.just_pressed(KeyCode::X) {
+///         settings.depth_test = !settings.depth_test;
+///     }
+/// }
+/// ```
+#[derive(Resource, ExtractResource, Debug, Clone)]
+pub struct DebugLinesSettings {
+    /// Whether lines are hidden behind other geometry, unless overridden with
+    /// [`DebugLines::depth_test`]. See [`DebugLinesPlugin::with_depth_test`].
+    pub depth_test: bool,
+}
+
+#[derive(Resource)]
+pub(crate) struct DebugLinesRenderLayer {
+    render_layers: Vec<u8>,
+}
+
+/// The `SystemSet` in which the debug lines update system runs.
+///
+/// This set is nested in `CoreSet::PostUpdate`, so it runs after all update systems.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum DebugLinesSet {
+    DrawLines,
+}
 
-// This module exists to "isolate" the `#[cfg]` attributes to this part of the
-// code. Otherwise, we would pollute the code with a lot of feature
-// gates-specific code.
-#[cfg(feature = "3d")]
-mod dim {
-    use bevy::{asset::Handle, render::mesh::Mesh};
-    pub(crate) use bevy::core_pipeline::core_3d::Opaque3d as Phase;
+/// The kind of camera a line is drawn for.
+///
+/// 3d lines are rendered by cameras with a `Camera3d` component, 2d lines by cameras with a
+/// `Camera2d` component. Both can be used in the same app, see [`DebugLines::in_2d`] and
+/// [`DebugLines::in_3d`].
+///
+/// The default dimension is [`LineDimension::ThreeD`] when the `3d` feature is enabled, and
+/// [`LineDimension::TwoD`] otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineDimension {
+    TwoD,
+    ThreeD,
+}
 
-    pub(crate) use crate::render_dim::r3d::{DebugLinePipeline, DrawDebugLines, queue};
+impl Default for LineDimension {
+    fn default() -> Self {
+        if cfg!(feature = "3d") {
+            LineDimension::ThreeD
+        } else {
+            LineDimension::TwoD
+        }
+    }
+}
 
-    pub(crate) type MeshHandle = Handle<Mesh>;
+/// The coordinate space the points of a line are given in.
+///
+/// Lines in [`LineSpace::Ndc`] and [`LineSpace::Screen`] ignore the camera transform and
+/// projection, they are drawn on top of everything else and can be used for HUDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LineSpace {
+    /// World space, transformed by the camera.
+    #[default]
+    World,
+    /// Normalized device coordinates, from `(-1, -1)` at the bottom left of the viewport to
+    /// `(1, 1)` at the top right. The `z` coordinate is ignored.
+    Ndc,
+    /// Physical pixels, from `(0, 0)` at the top left of the viewport, like
+    /// `Window::physical_cursor_position`. The `z` coordinate is ignored.
+    Screen,
+}
+
+/// The width of a line.
+///
+/// Lines are 1 pixel wide by default, wider lines are drawn as quads facing the camera.
+/// A plain `f32` converts to a width in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineWidth {
+    /// Width in pixels, the line has the same width on screen regardless of its distance to the camera.
+    Pixels(f32),
+    /// Width in world units, the line gets thinner the further it is from the camera.
+    World(f32),
+}
 
-    pub(crate) fn from_handle(from: &MeshHandle) -> &Handle<Mesh> {
-        from
+impl Default for LineWidth {
+    fn default() -> Self {
+        LineWidth::Pixels(1.0)
     }
+}
 
-    pub(crate) fn into_handle(from: Handle<Mesh>) -> MeshHandle {
-        from
+impl From<f32> for LineWidth {
+    fn from(pixels: f32) -> Self {
+        LineWidth::Pixels(pixels)
     }
+}
 
-    pub(crate) const DIMMENSION: &str = "3d";
+impl LineWidth {
+    // The mode used to draw lines of this width, and the width to give to the shader.
+    fn mode(self) -> (LineMode, f32) {
+        match self {
+            LineWidth::Pixels(width) if width <= 1.0 => (LineMode::Thin, width),
+            LineWidth::Pixels(width) => (LineMode::Pixels, width),
+            LineWidth::World(width) => (LineMode::World, width),
+        }
+    }
+}
+
+/// A dash pattern lines are drawn with, see [`DebugLines::dash`].
+///
+/// ```
+/// use bevy_prototype_debug_lines::*;
+///
+/// // Dashes of 10 pixels every 15 pixels, moving along the lines at 20 pixels per second.
+/// let dash = LineDash::pixels(10.0, 5.0).with_speed(20.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineDash {
+    /// Length of the dashes.
+    pub dash: f32,
+    /// Length of the gaps between the dashes.
+    pub gap: f32,
+    pub units: DashUnits,
+    /// Speed the dashes move at along the line, from its start to its end, in `units` per second.
+    pub speed: f32,
 }
 
-#[cfg(not(feature = "3d"))]
-mod dim {
-    use bevy::{asset::Handle, render::mesh::Mesh, sprite::Mesh2dHandle};
-    pub(crate) use bevy::core_pipeline::core_2d::Transparent2d as Phase;
+impl LineDash {
+    /// Dashes and gaps measured in world units, they get shorter the further they are from the camera.
+    pub fn world(dash: f32, gap: f32) -> Self {
+        Self {
+            dash,
+            gap,
+            units: DashUnits::World,
+            speed: 0.0,
+        }
+    }
 
-    pub(crate) use crate::render_dim::r2d::{DebugLinePipeline, DrawDebugLines, queue};
+    /// Dashes and gaps measured in pixels, they have the same length on screen regardless of their
+    /// distance to the camera.
+    pub fn pixels(dash: f32, gap: f32) -> Self {
+        Self {
+            units: DashUnits::Pixels,
+            ..Self::world(dash, gap)
+        }
+    }
 
-    pub(crate) type MeshHandle = Mesh2dHandle;
+    /// Dots 2 pixels long, 6 pixels apart.
+    pub fn dotted() -> Self {
+        Self::pixels(2.0, 4.0)
+    }
 
-    pub(crate) fn from_handle(from: &MeshHandle) -> &Handle<Mesh> {
-        &from.0
+    /// Sets the speed the dashes move at along the line.
+    pub fn with_speed(self, speed: f32) -> Self {
+        Self { speed, ..self }
     }
 
-    pub(crate) fn into_handle(from: Handle<Mesh>) -> MeshHandle {
-        Mesh2dHandle(from)
+    // The dash vertex attribute of the start (`t = 0`) or end (`t = 1`) of a line, both shifted
+    // when the line starts further into the pattern.
+    fn attribute(self, t: f32) -> [f32; 4] {
+        [t, self.dash, self.gap, self.speed]
     }
+}
 
-    pub(crate) const DIMMENSION: &str = "2d";
+/// The units the lengths of a [`LineDash`] are measured in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DashUnits {
+    #[default]
+    World,
+    Pixels,
 }
 
-// See debuglines.wgsl for explanation on 2 shaders.
-//pub(crate) const SHADER_FILE: &str = include_str!("debuglines.wgsl");
-pub(crate) const DEBUG_LINES_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(17477439189930443325);
+/// The shape of the markers drawn with [`DebugLines::point`], see [`DebugLines::point_marker`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PointMarker {
+    #[default]
+    Square,
+    Circle,
+    /// A diagonal cross.
+    Cross,
+}
 
-#[derive(Resource)]
-pub(crate) struct DebugLinesConfig {
-    depth_test: bool,
+/// Default size of the markers drawn with [`DebugLines::point`], in pixels.
+pub const DEFAULT_POINT_SIZE: f32 = 6.0;
+
+// Settings applied to the lines added to `DebugLines`, changed through `DebugLinesScope`s.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LineStyle {
+    pub(crate) dimension: LineDimension,
+    pub(crate) width: LineWidth,
+    pub(crate) depth_test: Option<bool>,
+    pub(crate) group: Option<&'static str>,
+    pub(crate) render_layers: Option<RenderLayers>,
+    pub(crate) camera: Option<Entity>,
+    pub(crate) space: LineSpace,
+    pub(crate) dash: Option<LineDash>,
+    pub(crate) text_size: TextSize,
+    pub(crate) point_marker: PointMarker,
+    // Overrides `DEFAULT_POINT_SIZE`.
+    pub(crate) point_size: Option<f32>,
+    // Draws the lines in the transparent pass, so the alpha of their colors shows.
+    pub(crate) translucent: bool,
+    // How far into the dash pattern the lines start, in world units. Only applies to patterns
+    // measured in world units, since lengths in pixels change with the view.
+    pub(crate) dash_offset: f32,
 }
 
-#[derive(Resource)]
-pub(crate) struct DebugLinesRenderLayer {
-    render_layers: Vec<u8>,
+/// Settings of a named group of lines, see [`DebugLines::group`].
+#[derive(Debug, Clone, Copy)]
+pub struct LineGroupSettings {
+    /// Whether the lines of the group are drawn.
+    pub enabled: bool,
+    /// Draws all the lines of the group with this color instead of their own, if set.
+    pub color: Option<Color>,
+    /// Draws the lines of the group on these render layers instead of the plugin ones, if set.
+    /// Lines added with [`DebugLines::render_layers`] keep their own layers.
+    pub render_layers: Option<RenderLayers>,
+    /// Draws the lines of the group only for this camera, if set.
+    /// Lines added with [`DebugLines::for_camera`] keep their own camera.
+    pub camera: Option<Entity>,
 }
 
-/// The `SystemSet` in which the debug lines update system runs.
-///
-/// This set is nested in `CoreSet::PostUpdate`, so it runs after all update systems.
-#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
-pub enum DebugLinesSet {
-    DrawLines,
+impl Default for LineGroupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: None,
+            render_layers: None,
+            camera: None,
+        }
+    }
 }
 
 /// Bevy plugin, for initializing stuff.
@@ -125,10 +321,27 @@ pub enum DebugLinesSet {
 ///     .add_plugins(DebugLinesPlugin::with_layers(vec![0, 1, 5]))
 ///     .run();
 /// ```
+/// Options can be combined by setting the fields directly.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(DebugLinesPlugin {
+///         max_lines: 1_000_000,
+///         ..DebugLinesPlugin::with_depth_test(true)
+///     })
+///     .run();
+/// ```
 #[derive(Debug, Clone)]
 pub struct DebugLinesPlugin {
-    depth_test: bool,
-    render_layers: Vec<u8>,
+    /// See [`DebugLinesPlugin::with_depth_test`].
+    pub depth_test: bool,
+    /// See [`DebugLinesPlugin::with_layers`].
+    pub render_layers: Vec<u8>,
+    /// See [`DebugLinesPlugin::with_max_lines`].
+    pub max_lines: usize,
 }
 
 impl Default for DebugLinesPlugin {
@@ -136,6 +349,7 @@ impl Default for DebugLinesPlugin {
         Self {
             depth_test: false,
             render_layers: vec![0], // All entitities are renderered in layer 0 if not otherwise specified.
+            max_lines: MAX_LINES,
         }
     }
 }
@@ -148,6 +362,9 @@ impl DebugLinesPlugin {
     ///
     /// * `val` - True if lines should intersect with other geometry, or false
     ///   if lines should always draw on top be drawn on top (the default).
+    ///
+    /// This can be overridden for specific lines with [`DebugLines::depth_test`], and changed at
+    /// runtime through [`DebugLinesSettings`].
     pub fn with_depth_test(val: bool) -> Self {
         Self {
             depth_test: val,
@@ -159,6 +376,8 @@ impl DebugLinesPlugin {
     /// Cameras will only render entities on layers which intersect with the camera's own [`RenderLayers`] component.
     /// If not specified, the debug line entity will be on layer 0 by default.
     ///
+    /// This can be overridden for specific lines with [`DebugLines::render_layers`].
+    ///
     /// # Arguments
     ///
     /// * `layers` - The list of rendering layers.
@@ -168,23 +387,38 @@ impl DebugLinesPlugin {
             ..default()
         }
     }
+
+    /// Controls how many lines can be drawn at once, [`MAX_LINES`] by default.
+    /// Lines added past this limit are ignored. Meshes to hold the lines are spawned as needed, so a
+    /// high limit does not cost anything until the lines are actually drawn. Meshes which are not
+    /// needed anymore are despawned after 60 frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_lines` - The maximum number of lines.
+    pub fn with_max_lines(max_lines: usize) -> Self {
+        Self {
+            max_lines,
+            ..default()
+        }
+    }
 }
 
 impl Plugin for DebugLinesPlugin {
     fn build(&self, app: &mut App) {
-        use bevy::render::{render_resource::SpecializedMeshPipelines, RenderApp, RenderSet};
-
-        #[cfg(feature = "3d")]
-        {
-            load_internal_asset!(app, DEBUG_LINES_SHADER_HANDLE, "debuglines.wgsl", Shader::from_wgsl);
-        }
+        use bevy::render::RenderApp;
 
-        #[cfg(not(feature = "3d"))]
-        {
-            load_internal_asset!(app, DEBUG_LINES_SHADER_HANDLE, "debuglines2d.wgsl", Shader::from_wgsl);
-        }
+        load_internal_asset!(app, DEBUG_LINES_SHADER_HANDLE, "debuglines.wgsl", Shader::from_wgsl);
+        load_internal_asset!(
+            app,
+            DEBUG_LINES_2D_SHADER_HANDLE,
+            "debuglines2d.wgsl",
+            Shader::from_wgsl
+        );
 
+        // Keep the lines of a `DebugLines` inserted before the plugin, only applying its limit.
         app.init_resource::<DebugLines>();
+        app.world.resource_mut::<DebugLines>().max_lines = self.max_lines;
 
         #[cfg(feature = "shapes")]
         app.init_resource::<DebugShapes>();
@@ -193,140 +427,357 @@ impl Plugin for DebugLinesPlugin {
             render_layers: self.render_layers.to_owned(),
         });
 
-        app.add_systems(Startup, setup)
-            // .add_systems(PostUpdate, (update, inspect_entities).in_set(DebugLinesSet::DrawLines));
-            .add_systems(PostUpdate, update.in_set(DebugLinesSet::DrawLines));
+        app.add_systems(Startup, setup);
+        // app.add_systems(PostUpdate, (update, inspect_entities).in_set(DebugLinesSet::DrawLines));
+        app.add_systems(
+            PostUpdate,
+            (
+                // The systems drawing lines for entities skip hidden ones. They run before visibility
+                // is propagated, see `EntityVisibility`.
+                (local::draw_local_lines, wireframe::draw_wireframes, normals::draw_normals)
+                    .after(TransformSystem::TransformPropagate),
+                update,
+                // Meshes spawned for new batches must be seen by the visibility systems to be drawn
+                // on the frame their lines were added.
+                apply_deferred,
+            )
+                .chain()
+                .in_set(DebugLinesSet::DrawLines)
+                .before(VisibilitySystems::VisibilityPropagate),
+        );
 
-        app.sub_app_mut(RenderApp)
-            .add_render_command::<dim::Phase, dim::DrawDebugLines>()
-            .insert_resource(DebugLinesConfig {
-                depth_test: self.depth_test,
-            })
-            .init_resource::<SpecializedMeshPipelines<dim::DebugLinePipeline>>()
-            .add_systems(ExtractSchedule, extract)
-            .add_systems(Render, dim::queue.in_set(RenderSet::Queue));
+        #[cfg(feature = "shapes")]
+        app.init_resource::<ShowAabbSettings>().add_systems(
+            PostUpdate,
+            (
+                // After the `Aabb`s of entities spawned this frame are inserted.
+                aabb::draw_aabbs.after(VisibilitySystems::CalculateBoundsFlush),
+                shapes::draw_axes,
+                shapes::draw_grids,
+            )
+                .after(TransformSystem::TransformPropagate)
+                .before(update)
+                .in_set(DebugLinesSet::DrawLines),
+        );
+
+        app.insert_resource(DebugLinesSettings {
+            depth_test: self.depth_test,
+        })
+        .add_plugins(ExtractResourcePlugin::<DebugLinesSettings>::default());
 
-        info!("Loaded {} debug lines plugin.", dim::DIMMENSION);
+        app.sub_app_mut(RenderApp).add_systems(ExtractSchedule, extract);
     }
 
-    // We can't add the pipeline to the app until after the render app has been initialized.
+    // We can't add the pipelines to the app until after the render app has been initialized.
+    // Each pipeline is only added if the matching bevy mesh pipeline exists, so that a 2d only
+    // app does not need the pbr plugin (and vice versa).
     fn finish(&self, app: &mut App) {
-        use bevy::render::RenderApp;
+        use bevy::{
+            core_pipeline::{
+                core_2d::Transparent2d,
+                core_3d::{Opaque3d, Transparent3d},
+            },
+            pbr::MeshPipeline,
+            render::{
+                render_phase::AddRenderCommand, render_resource::SpecializedMeshPipelines, Render, RenderApp, RenderSet,
+            },
+            sprite::Mesh2dPipeline,
+        };
+        use render_dim::{r2d, r3d};
+
+        let render_app = app.sub_app_mut(RenderApp);
+
+        let has_3d = render_app.world.contains_resource::<MeshPipeline>();
+        if has_3d {
+            render_app
+                .add_render_command::<Opaque3d, r3d::DrawDebugLines>()
+                .add_render_command::<Transparent3d, r3d::DrawDebugLines>()
+                .init_resource::<r3d::DebugLinePipeline>()
+                .init_resource::<SpecializedMeshPipelines<r3d::DebugLinePipeline>>()
+                .add_systems(Render, r3d::queue.in_set(RenderSet::Queue));
+        }
+
+        let has_2d = render_app.world.contains_resource::<Mesh2dPipeline>();
+        if has_2d {
+            render_app
+                .add_render_command::<Transparent2d, r2d::DrawDebugLines>()
+                .init_resource::<r2d::DebugLinePipeline>()
+                .init_resource::<SpecializedMeshPipelines<r2d::DebugLinePipeline>>()
+                .add_systems(Render, r2d::queue.in_set(RenderSet::Queue));
+        }
 
-        app.get_sub_app_mut(RenderApp)
-            .unwrap()
-            .init_resource::<dim::DebugLinePipeline>();
+        match (has_2d, has_3d) {
+            (true, true) => info!("Loaded 2d and 3d debug lines plugin."),
+            (true, false) => info!("Loaded 2d debug lines plugin."),
+            (false, true) => info!("Loaded 3d debug lines plugin."),
+            (false, false) => warn!("Loaded debug lines plugin without a 2d or 3d mesh pipeline, nothing will render."),
+        }
     }
 }
 
-// Number of meshes to separate line buffers into.
+// Default number of meshes worth of lines that can be drawn.
 // We don't really do culling currently but this is a gateway to that.
 const MESH_COUNT: usize = 4;
-// Maximum number of points for each individual mesh.
+// Maximum number of points for each individual mesh, more meshes are spawned as lines are added.
 const MAX_POINTS_PER_MESH: usize = 2_usize.pow(16);
+// Number of frames a mesh without lines is kept for before being despawned.
+const UNUSED_MESH_FRAMES: u32 = 60;
 const _MAX_LINES_PER_MESH: usize = MAX_POINTS_PER_MESH / 2;
-/// Maximum number of points.
+/// Default maximum number of points.
 pub const MAX_POINTS: usize = MAX_POINTS_PER_MESH * MESH_COUNT;
-/// Maximum number of unique lines to draw at once.
+/// Default maximum number of unique lines to draw at once, see [`DebugLinesPlugin::with_max_lines`].
 pub const MAX_LINES: usize = MAX_POINTS / 2;
 
 pub const MESH_PADDING: MeshVertexAttribute = MeshVertexAttribute::new("padding", 2, VertexFormat::Float32);
+// The other end of the line a vertex belongs to, used to expand thick lines into quads.
+pub(crate) const ATTRIBUTE_LINE_OTHER: MeshVertexAttribute =
+    MeshVertexAttribute::new("LineOther", 1_774_206_811, VertexFormat::Float32x3);
+// The width of the line, and the side of the line the vertex should be moved to.
+pub(crate) const ATTRIBUTE_LINE_PARAMS: MeshVertexAttribute =
+    MeshVertexAttribute::new("LineParams", 1_774_206_812, VertexFormat::Float32x2);
+// Whether the vertex is at the start (0) or end (1) of the line, and the dash, gap and speed of its
+// dash pattern.
+pub(crate) const ATTRIBUTE_LINE_DASH: MeshVertexAttribute =
+    MeshVertexAttribute::new("LineDash", 1_774_206_813, VertexFormat::Float32x4);
+// The offset of a vertex of text or of a point marker from the position it is anchored at, in pixels
+// or world units.
+pub(crate) const ATTRIBUTE_ANCHOR_OFFSET: MeshVertexAttribute =
+    MeshVertexAttribute::new("AnchorOffset", 1_774_206_814, VertexFormat::Float32x2);
 
+// Spawns a mesh for the lines drawn with the default settings, which is always kept around so
+// they don't need to wait for a new mesh.
 fn setup(mut cmds: Commands, mut meshes: ResMut<Assets<Mesh>>, config: Res<DebugLinesRenderLayer>) {
-    // Spawn a bunch of meshes to use for lines.
-    for i in 0..MESH_COUNT {
-        // Create a new mesh with the number of vertices we need.
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+    spawn_mesh(&mut cmds, &mut meshes, LineBatch::default(), 0, Vec3::ZERO, &config);
+}
+
+// Spawns a new mesh to use for lines of the given batch.
+fn spawn_mesh(
+    cmds: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    batch: LineBatch,
+    index: usize,
+    center: Vec3,
+    config: &DebugLinesRenderLayer,
+) -> Handle<Mesh> {
+    let render_layers = match batch.render_layers {
+        Some(mask) => (0..RenderLayers::TOTAL_LAYERS as u8)
+            .filter(|layer| mask & 1 << layer != 0)
+            .collect(),
+        None => RenderLayers::from_layers(config.render_layers.as_slice()),
+    };
+
+    // Create a new mesh with empty buffers, which grow when lines are added.
+    let mut mesh = Mesh::new(batch.mode.topology());
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_POSITION,
+        VertexAttributeValues::Float32x3(vec![]),
+    );
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_COLOR,
+        VertexAttributeValues::Float32x4(vec![]),
+    );
+
+    if batch.mode.is_thick() || batch.dash.is_some() {
         mesh.insert_attribute(
-            Mesh::ATTRIBUTE_POSITION,
-            VertexAttributeValues::Float32x3(Vec::with_capacity(MAX_POINTS_PER_MESH)),
+            ATTRIBUTE_LINE_OTHER,
+            VertexAttributeValues::Float32x3(vec![]),
         );
+    }
+    if batch.mode.is_thick() {
         mesh.insert_attribute(
-            Mesh::ATTRIBUTE_COLOR,
-            VertexAttributeValues::Float32x4(Vec::with_capacity(MAX_POINTS_PER_MESH)),
+            ATTRIBUTE_LINE_PARAMS,
+            VertexAttributeValues::Float32x2(vec![]),
         );
-
-        // This is needed only to keep padding aligned with 16 bytes in WASM
-        #[cfg(target_arch = "wasm32")]
+    }
+    if batch.dash.is_some() {
+        mesh.insert_attribute(
+            ATTRIBUTE_LINE_DASH,
+            VertexAttributeValues::Float32x4(vec![]),
+        );
+    }
+    if batch.mode.is_anchored() {
         mesh.insert_attribute(
-            MESH_PADDING,
-            VertexAttributeValues::Float32(Vec::with_capacity(MAX_POINTS_PER_MESH)),
+            ATTRIBUTE_ANCHOR_OFFSET,
+            VertexAttributeValues::Float32x2(vec![]),
         );
+    }
 
-        // https://github.com/Toqozz/bevy_debug_lines/issues/16
-        //mesh.set_indices(Some(Indices::U16(Vec::with_capacity(MAX_POINTS_PER_MESH))));
+    // This is needed only to keep padding aligned with 16 bytes in WASM
+    #[cfg(target_arch = "wasm32")]
+    insert_padding(&mut mesh);
 
-        cmds.spawn((
-            TransformBundle::default(),
-            VisibilityBundle::default(),
-            dim::into_handle(meshes.add(mesh)),
-            NotShadowReceiver,
-            TransmittedShadowReceiver,
-            NotShadowCaster,
-            NoAutomaticBatching,
-            DebugLinesMesh(i),
-            NoFrustumCulling, // disable frustum culling
-            RenderLayers::from_layers(config.render_layers.as_slice()),
-        ));
-    }
+    // https://github.com/Toqozz/bevy_debug_lines/issues/16
+    //mesh.set_indices(Some(Indices::U16(Vec::with_capacity(MAX_POINTS_PER_MESH))));
+
+    let mesh = meshes.add(mesh);
+    let mut entity = cmds.spawn((
+        TransformBundle::default(),
+        VisibilityBundle::default(),
+        NotShadowReceiver,
+        TransmittedShadowReceiver,
+        NotShadowCaster,
+        NoAutomaticBatching,
+        DebugLinesMesh {
+            batch,
+            index,
+            mesh: mesh.clone(),
+            center,
+            unused_frames: 0,
+        },
+        NoFrustumCulling, // disable frustum culling
+        render_layers,
+    ));
+    match batch.dimension {
+        LineDimension::TwoD => entity.insert(Mesh2dHandle(mesh.clone())),
+        LineDimension::ThreeD => entity.insert(mesh.clone()),
+    };
+
+    mesh
+}
+
+// Adds a padding attribute filling the vertices of the mesh up to a multiple of 16 bytes.
+#[cfg(target_arch = "wasm32")]
+fn insert_padding(mesh: &mut Mesh) {
+    use bevy::render::mesh::VertexFormatSize;
+    use VertexAttributeValues::{Float32, Float32x2, Float32x3};
+
+    let size: u64 = mesh.attributes().map(|(_, values)| VertexFormat::from(values).get_size()).sum();
+    let padding = match size % 16 {
+        4 => Float32x3(vec![]),
+        8 => Float32x2(vec![]),
+        12 => Float32(vec![]),
+        _ => return,
+    };
+    let format = VertexFormat::from(&padding);
+    mesh.insert_attribute(MeshVertexAttribute { format, ..MESH_PADDING }, padding);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update(
-    debug_line_meshes: Query<(&dim::MeshHandle, &DebugLinesMesh)>,
+    mut cmds: Commands,
+    mut debug_line_meshes: Query<(Entity, &mut DebugLinesMesh)>,
+    cameras: Query<(), With<Camera>>,
     time: Res<Time>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut lines: ResMut<DebugLines>,
     mut shapes: ResMut<DebugShapes>,
+    config: Res<DebugLinesRenderLayer>,
 ) {
     // Add lines from shapes
     #[cfg(feature = "shapes")]
     {
-        for shape in &shapes.shapes {
-            shape.add_lines(&mut lines);
+        for (shape, style) in shapes.styled() {
+            shape.add_lines(&mut lines.with_style(style));
         }
-        shapes.shapes.clear();
+        shapes.clear();
     }
 
-    // For each debug line mesh, fill its buffers with the relevant positions/colors chunks.
-    for (mesh_handle, debug_lines_idx) in debug_line_meshes.iter() {
-        let mesh = meshes.get_mut(dim::from_handle(mesh_handle)).unwrap();
-        use VertexAttributeValues::{Float32x3, Float32x4};
+    // Forget the lines of cameras which were despawned, so their meshes are despawned too.
+    lines.remove_camera_lines(|camera| cameras.contains(camera));
 
-        let position_chunk = if lines.enabled {
-            lines.positions.chunks(MAX_POINTS_PER_MESH).nth(debug_lines_idx.0)
-        } else {
-            None
-        };
+    // Build the vertices for each batch of lines.
+    let mut batches = lines.batches();
+    batches.retain(|batch, _| match batch.camera {
+        Some(camera) => cameras.contains(camera),
+        None => true,
+    });
 
-        let color_chunk = if lines.enabled {
-            lines.colors.chunks(MAX_POINTS_PER_MESH).nth(debug_lines_idx.0)
+    // Number of meshes needed to hold the lines of a batch.
+    let mesh_count = |batch: &LineBatch| {
+        batches
+            .get(batch)
+            .map_or(0, |vertices| vertices.positions.len().div_ceil(batch.mode.vertices_per_mesh()))
+    };
+
+    // Center of the vertices of a mesh, to sort translucent meshes from back to front. Meshes are
+    // shared by all the cameras, so what is inside them can't be sorted for each view.
+    let center = |batch: &LineBatch, index: usize| {
+        let chunk = batches
+            .get(batch)
+            .filter(|_| batch.is_translucent())
+            .and_then(|vertices| vertices.positions.chunks(batch.mode.vertices_per_mesh()).nth(index));
+        match chunk {
+            Some(positions) => {
+                positions.iter().map(|&position| Vec3::from(position)).sum::<Vec3>() / positions.len() as f32
+            },
+            None => Vec3::ZERO,
+        }
+    };
+
+    // Despawn the meshes which haven't been needed for a while, except the first one of the default
+    // batch. They are kept around a bit so batches used every few frames don't respawn them.
+    let mut mesh_pool = vec![];
+    for (entity, mut m) in &mut debug_line_meshes {
+        if m.index < mesh_count(&m.batch) {
+            m.unused_frames = 0;
         } else {
-            None
-        };
+            m.unused_frames += 1;
+        }
+        if m.unused_frames <= UNUSED_MESH_FRAMES || (m.batch == LineBatch::default() && m.index == 0) {
+            if m.batch.is_translucent() {
+                m.center = center(&m.batch, m.index);
+            }
+            mesh_pool.push((m.batch, m.index, m.mesh.clone()));
+        } else {
+            cmds.entity(entity).despawn();
+        }
+    }
+
+    // Spawn more meshes if the existing ones can't hold all the lines.
+    for batch in batches.keys() {
+        let existing = mesh_pool.iter().filter(|(b, _, _)| b == batch).count();
+        for index in existing..mesh_count(batch) {
+            let mesh = spawn_mesh(&mut cmds, &mut meshes, *batch, index, center(batch, index), &config);
+            mesh_pool.push((*batch, index, mesh));
+        }
+    }
+
+    // For each debug line mesh, fill its buffers with the relevant vertex chunks.
+    for (batch, index, mesh_handle) in mesh_pool {
+        // Leave the meshes which are already empty untouched, so they aren't sent to the GPU again.
+        if index >= mesh_count(&batch) && meshes.get(&mesh_handle).is_some_and(|mesh| mesh.count_vertices() == 0) {
+            continue;
+        }
+        let mesh = meshes.get_mut(&mesh_handle).unwrap();
+        let chunk_size = batch.mode.vertices_per_mesh();
+        let vertices = batches.get(&batch);
+        use VertexAttributeValues::{Float32x2, Float32x3, Float32x4};
 
         if let Some(Float32x3(buffer)) = mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION) {
-            buffer.clear();
-            if let Some(new_content) = position_chunk {
-                buffer.extend(new_content);
-            }
+            fill_chunk(buffer, vertices.map(|v| &v.positions[..]), chunk_size, index);
         }
 
         if let Some(Float32x4(buffer)) = mesh.attribute_mut(Mesh::ATTRIBUTE_COLOR) {
-            buffer.clear();
-            if let Some(new_content) = color_chunk {
-                buffer.extend(new_content);
-            }
+            fill_chunk(buffer, vertices.map(|v| &v.colors[..]), chunk_size, index);
+        }
+
+        if let Some(Float32x3(buffer)) = mesh.attribute_mut(ATTRIBUTE_LINE_OTHER) {
+            fill_chunk(buffer, vertices.map(|v| &v.others[..]), chunk_size, index);
+        }
+
+        if let Some(Float32x2(buffer)) = mesh.attribute_mut(ATTRIBUTE_LINE_PARAMS) {
+            fill_chunk(buffer, vertices.map(|v| &v.params[..]), chunk_size, index);
+        }
+
+        if let Some(Float32x4(buffer)) = mesh.attribute_mut(ATTRIBUTE_LINE_DASH) {
+            fill_chunk(buffer, vertices.map(|v| &v.dashes[..]), chunk_size, index);
+        }
+
+        if let Some(Float32x2(buffer)) = mesh.attribute_mut(ATTRIBUTE_ANCHOR_OFFSET) {
+            fill_chunk(buffer, vertices.map(|v| &v.offsets[..]), chunk_size, index);
         }
 
         // This is needed only to keep padding aligned with 16 bytes in WASM
         #[cfg(target_arch = "wasm32")] {
             use VertexAttributeValues::Float32;
 
-            if let Some(Float32(buffer)) = mesh.attribute_mut(crate::MESH_PADDING) {
-                buffer.clear();
-                if let Some(new_content) = position_chunk {
-                    buffer.extend(&vec![0.0; new_content.len()]);
-                }
+            // Not `count_vertices`, which would be limited by the padding of the previous frame.
+            let len = mesh.attribute(Mesh::ATTRIBUTE_POSITION).map_or(0, |positions| positions.len());
+            match mesh.attribute_mut(crate::MESH_PADDING) {
+                Some(Float32(buffer)) => buffer.resize(len, 0.0),
+                Some(Float32x2(buffer)) => buffer.resize(len, [0.0; 2]),
+                Some(Float32x3(buffer)) => buffer.resize(len, [0.0; 3]),
+                _ => {},
             }
         }
 
@@ -347,19 +798,241 @@ fn update(
     lines.update(time.delta_seconds());
 }
 
+// Replaces the content of a mesh buffer with the nth chunk of `content`.
+fn fill_chunk<T: Copy>(buffer: &mut Vec<T>, content: Option<&[T]>, chunk_size: usize, index: usize) {
+    buffer.clear();
+    if let Some(new_content) = content.and_then(|c| c.chunks(chunk_size).nth(index)) {
+        buffer.extend(new_content);
+    }
+}
+
 /// Move the DebugLinesMesh marker Component to the render context.
-fn extract(mut commands: Commands, query: Extract<Query<Entity, With<DebugLinesMesh>>>) {
-    for entity in query.iter() {
-        commands.get_or_spawn(entity).insert(RenderDebugLinesMesh);
+fn extract(mut commands: Commands, query: Extract<Query<(Entity, &DebugLinesMesh)>>) {
+    for (entity, debug_lines_mesh) in query.iter() {
+        commands
+            .get_or_spawn(entity)
+            .insert(RenderDebugLinesMesh(debug_lines_mesh.batch, debug_lines_mesh.center));
     }
 }
 
 /// Marker component for the debug lines mesh in the world.
 #[derive(Component)]
-pub struct DebugLinesMesh(usize);
+pub struct DebugLinesMesh {
+    batch: LineBatch,
+    index: usize,
+    mesh: Handle<Mesh>,
+    // Center of the vertices of the mesh, if its batch is translucent.
+    center: Vec3,
+    // Number of frames in a row the mesh had no lines to draw.
+    unused_frames: u32,
+}
 
+// The batch of a debug lines mesh, and the center of its vertices.
 #[derive(Component)]
-struct RenderDebugLinesMesh;
+struct RenderDebugLinesMesh(LineBatch, Vec3);
+
+impl RenderDebugLinesMesh {
+    // Whether the lines should be drawn for the given view, which has the same entity as its camera.
+    fn is_drawn_for(&self, view: Entity) -> bool {
+        match self.0.camera {
+            Some(camera) => camera == view,
+            None => true,
+        }
+    }
+}
+
+// How the vertices of a line mesh are laid out, and turned into lines by the shader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum LineMode {
+    // 1 pixel wide lines, drawn as a line list.
+    Thin,
+    // Quads expanded to a width in pixels.
+    Pixels,
+    // Quads expanded to a width in world units.
+    World,
+    // Filled triangles.
+    Filled,
+    // Lines of text, offset in pixels from the position they are anchored at.
+    PixelText,
+    // Lines of text, offset in world units along the axes of the camera.
+    WorldText,
+    // Quads around points, offset in pixels and shaped into markers.
+    Points(PointMarker),
+}
+
+impl LineMode {
+    pub(crate) fn topology(self) -> PrimitiveTopology {
+        match self {
+            LineMode::Thin | LineMode::PixelText | LineMode::WorldText => PrimitiveTopology::LineList,
+            LineMode::Pixels | LineMode::World | LineMode::Filled | LineMode::Points(_) => {
+                PrimitiveTopology::TriangleList
+            },
+        }
+    }
+
+    // Whether lines are expanded into quads by the shader.
+    pub(crate) fn is_thick(self) -> bool {
+        matches!(self, LineMode::Pixels | LineMode::World)
+    }
+
+    // Whether vertices are offset by the shader from the position they are anchored at, to draw
+    // text or points.
+    pub(crate) fn is_anchored(self) -> bool {
+        matches!(self, LineMode::PixelText | LineMode::WorldText | LineMode::Points(_))
+    }
+
+    // Number of vertices of a line, of a triangle or of a point.
+    fn vertices_per_line(self) -> usize {
+        match self {
+            LineMode::Thin | LineMode::PixelText | LineMode::WorldText => 2,
+            LineMode::Filled => 3,
+            LineMode::Pixels | LineMode::World | LineMode::Points(_) => 6,
+        }
+    }
+
+    // Number of vertices in a full mesh, so that lines are never split between two meshes.
+    fn vertices_per_mesh(self) -> usize {
+        MAX_POINTS_PER_MESH - MAX_POINTS_PER_MESH % self.vertices_per_line()
+    }
+}
+
+// Lines of the same batch are drawn with the same meshes and pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct LineBatch {
+    pub(crate) dimension: LineDimension,
+    pub(crate) mode: LineMode,
+    pub(crate) space: LineSpace,
+    // Overrides the plugin depth test setting.
+    pub(crate) depth_test: Option<bool>,
+    // Overrides the plugin render layers, as a bit mask since `RenderLayers` can't be hashed.
+    pub(crate) render_layers: Option<u32>,
+    // The only camera the lines are drawn for.
+    pub(crate) camera: Option<Entity>,
+    // The units of the dash pattern of the lines, if they are dashed.
+    pub(crate) dash: Option<DashUnits>,
+    // Filled triangles are always translucent.
+    pub(crate) translucent: bool,
+}
+
+impl Default for LineBatch {
+    // The batch of lines drawn with the default settings.
+    fn default() -> Self {
+        LineBatch::new(&LineStyle::default(), None, LineMode::Thin, None)
+    }
+}
+
+impl LineBatch {
+    // Whether the lines are drawn in the transparent pass without writing depth, so they don't hide
+    // what is drawn after them. Each mesh is sorted as a whole, by the center of its vertices, the
+    // lines and triangles within it are drawn in the order they were added.
+    pub(crate) fn is_translucent(&self) -> bool {
+        self.translucent
+    }
+
+    // The batch of lines or triangles drawn with the given style and group settings.
+    fn new(style: &LineStyle, group: Option<&LineGroupSettings>, mode: LineMode, dash: Option<DashUnits>) -> Self {
+        let render_layers = style.render_layers.or_else(|| group.and_then(|group| group.render_layers));
+        LineBatch {
+            dimension: style.dimension,
+            mode,
+            space: style.space,
+            // Screen space lines are drawn on top of everything, and 2d lines ignore the setting.
+            depth_test: match (style.dimension, style.space) {
+                (LineDimension::TwoD, _) => None,
+                (_, LineSpace::World) => style.depth_test,
+                _ => Some(false),
+            },
+            render_layers: render_layers.map(layer_mask),
+            camera: style.camera.or_else(|| group.and_then(|group| group.camera)),
+            dash,
+            translucent: style.translucent || mode == LineMode::Filled,
+        }
+    }
+}
+
+fn layer_mask(render_layers: RenderLayers) -> u32 {
+    render_layers.iter().fold(0, |mask, layer| mask | 1 << layer)
+}
+
+// Tells whether the lines of an entity are drawn, before bevy propagates visibility for this frame.
+// The entity's own `Visibility` is up to date, but the one inherited from its parent is from the
+// previous frame. Entities without visibility components are always drawn.
+#[derive(SystemParam)]
+pub(crate) struct EntityVisibility<'w, 's> {
+    entities: Query<'w, 's, (&'static Visibility, Option<&'static Parent>)>,
+    parents: Query<'w, 's, &'static InheritedVisibility>,
+}
+
+impl EntityVisibility<'_, '_> {
+    pub(crate) fn is_visible(&self, entity: Entity) -> bool {
+        match self.entities.get(entity) {
+            Ok((Visibility::Hidden, _)) => false,
+            Ok((Visibility::Inherited, Some(parent))) => {
+                self.parents.get(parent.get()).copied().unwrap_or(InheritedVisibility::VISIBLE).get()
+            },
+            _ => true,
+        }
+    }
+}
+
+// Vertex buffers for a batch of lines.
+#[derive(Default)]
+struct LineVertices {
+    positions: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+    others: Vec<[f32; 3]>,
+    params: Vec<[f32; 2]>,
+    dashes: Vec<[f32; 4]>,
+    offsets: Vec<[f32; 2]>,
+}
+
+impl LineVertices {
+    fn push_thin(
+        &mut self,
+        start: [f32; 3],
+        end: [f32; 3],
+        start_color: [f32; 4],
+        end_color: [f32; 4],
+        dash: Option<[[f32; 4]; 2]>,
+    ) {
+        self.positions.extend([start, end]);
+        self.colors.extend([start_color, end_color]);
+        if let Some(dash) = dash {
+            // The shader needs both ends of the line to know how far along it a fragment is.
+            self.others.extend([end, start]);
+            self.dashes.extend(dash);
+        }
+    }
+
+    fn push_thick(
+        &mut self,
+        start: [f32; 3],
+        end: [f32; 3],
+        start_color: [f32; 4],
+        end_color: [f32; 4],
+        width: f32,
+        dash: Option<[[f32; 4]; 2]>,
+    ) {
+        // Two triangles forming a quad around the line. The side is flipped for the end vertices
+        // because the shader computes it relative to the direction towards the other end.
+        let corners = [(true, 1.0), (true, -1.0), (false, -1.0), (false, -1.0), (true, -1.0), (false, 1.0)];
+        for (at_start, side) in corners {
+            if at_start {
+                self.positions.push(start);
+                self.others.push(end);
+                self.colors.push(start_color);
+            } else {
+                self.positions.push(end);
+                self.others.push(start);
+                self.colors.push(end_color);
+            }
+            self.params.push([width, side]);
+            if let Some(dash) = dash {
+                self.dashes.push(if at_start { dash[0] } else { dash[1] });
+            }
+        }
+    }
+}
 
 /// Bevy resource providing facilities to draw lines.
 ///
@@ -385,27 +1058,366 @@ struct RenderDebugLinesMesh;
 ///     );
 /// }
 /// ```
+///
+/// Lines are drawn in the default [`LineDimension`], use [`DebugLines::in_2d`] or
+/// [`DebugLines::in_3d`] to draw them for the other kind of camera.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// // Draws a line in the 3d world, and one on a 2d overlay.
+/// fn some_system(mut lines: ResMut<DebugLines>) {
+///     lines.in_3d().line(Vec3::ZERO, Vec3::Y, 0.0);
+///     lines.in_2d().line(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0), 0.0);
+/// }
+/// ```
+///
+/// Thicker lines can be drawn with [`DebugLines::line_width`].
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn some_system(mut lines: ResMut<DebugLines>) {
+///     // 4 pixels wide.
+///     lines.line_width(4.0).line(Vec3::ZERO, Vec3::X, 0.0);
+///     // 0.1 world units wide.
+///     lines.line_width(LineWidth::World(0.1)).line(Vec3::ZERO, Vec3::Y, 0.0);
+/// }
+/// ```
+///
+/// Every line gets a [`LineId`], which can be used to change or remove it while it is alive.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// // Draws a line which stays until it is removed, following the player.
+/// fn some_system(
+///     mut lines: ResMut<DebugLines>,
+///     mut line: Local<Option<LineId>>,
+///     player: Query<&Transform>,
+/// ) {
+///     let id = *line.get_or_insert_with(|| lines.line(Vec3::ZERO, Vec3::ZERO, f32::INFINITY));
+///     for transform in player.iter() {
+///         lines.move_line(id, Vec3::ZERO, transform.translation);
+///     }
+/// }
+/// ```
 #[derive(Resource)]
 pub struct DebugLines {
     pub enabled: bool,
-    pub positions: Vec<[f32; 3]>,
-    pub colors: Vec<[f32; 4]>,
-    pub durations: Vec<f32>,
+    /// Maximum number of lines, see [`DebugLinesPlugin::with_max_lines`].
+    pub max_lines: usize,
+    lines: SlotMap<DebugLine>,
+    triangles: Vec<DebugTriangle>,
+    texts: Vec<DebugText>,
+    points: Vec<DebugPoint>,
+    groups: HashMap<&'static str, LineGroupSettings>,
+    style: LineStyle,
 }
 
 impl Default for DebugLines {
     fn default() -> Self {
         Self {
             enabled: true,
-            positions: vec![],
-            colors: vec![],
-            durations: vec![],
+            max_lines: MAX_LINES,
+            lines: SlotMap::default(),
+            triangles: Vec::new(),
+            texts: Vec::new(),
+            points: Vec::new(),
+            groups: HashMap::default(),
+            style: LineStyle::default(),
         }
     }
 }
 
+/// Identifies a line drawn with [`DebugLines`].
+///
+/// Ids are never reused, so an id for a line which expired or was removed will not refer to a
+/// newer line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineId(SlotKey);
+
+struct DebugLine {
+    start: Vec3,
+    end: Vec3,
+    start_color: [f32; 4],
+    end_color: [f32; 4],
+    duration: f32,
+    style: LineStyle,
+}
+
+struct DebugTriangle {
+    points: [Vec3; 3],
+    color: [f32; 4],
+    duration: f32,
+    style: LineStyle,
+}
+
+struct DebugText {
+    position: Vec3,
+    text: String,
+    color: [f32; 4],
+    duration: f32,
+    style: LineStyle,
+}
+
+struct DebugPoint {
+    position: Vec3,
+    color: [f32; 4],
+    duration: f32,
+    style: LineStyle,
+}
+
 impl DebugLines {
-    /// Draw a line in world space, or update an existing line
+    /// Draw the lines added through the returned scope for cameras of the given dimension.
+    ///
+    /// The scope dereferences to [`DebugLines`], so it can be used just like it:
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn minimap_system(mut lines: ResMut<DebugLines>) {
+    ///     let mut minimap = lines.dimension(LineDimension::TwoD);
+    ///     minimap.line(Vec3::ZERO, Vec3::X * 100.0, 0.0);
+    ///     minimap.line(Vec3::ZERO, Vec3::Y * 100.0, 0.0);
+    /// }
+    /// ```
+    pub fn dimension(&mut self, dimension: LineDimension) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.dimension = dimension)
+    }
+
+    /// Draw the lines added through the returned scope for 2d cameras.
+    ///
+    /// Short for [`DebugLines::dimension`].
+    pub fn in_2d(&mut self) -> DebugLinesScope<'_> {
+        self.dimension(LineDimension::TwoD)
+    }
+
+    /// Draw the lines added through the returned scope for 3d cameras.
+    ///
+    /// Short for [`DebugLines::dimension`].
+    pub fn in_3d(&mut self) -> DebugLinesScope<'_> {
+        self.dimension(LineDimension::ThreeD)
+    }
+
+    /// Draw the lines added through the returned scope with the given width.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the lines, either a [`LineWidth`] or a number of pixels.
+    pub fn line_width(&mut self, width: impl Into<LineWidth>) -> DebugLinesScope<'_> {
+        let width = width.into();
+        self.scoped(|style| style.width = width)
+    }
+
+    /// Draw the text added through the returned scope with the given size, see [`DebugLines::text`].
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Height of capital letters, either a [`TextSize`] or a number of pixels.
+    pub fn text_size(&mut self, size: impl Into<TextSize>) -> DebugLinesScope<'_> {
+        let size = size.into();
+        self.scoped(|style| style.text_size = size)
+    }
+
+    /// Draw the points added through the returned scope with the given marker, see
+    /// [`DebugLines::point`].
+    pub fn point_marker(&mut self, marker: PointMarker) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.point_marker = marker)
+    }
+
+    /// Draw the points added through the returned scope with markers of the given size in pixels,
+    /// instead of [`DEFAULT_POINT_SIZE`].
+    pub fn point_size(&mut self, size: f32) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.point_size = Some(size))
+    }
+
+    /// Draw the lines added through the returned scope with or without depth testing, regardless of
+    /// the [`DebugLinesSettings::depth_test`] setting. Only affects 3d lines.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn some_system(mut lines: ResMut<DebugLines>) {
+    ///     // Hidden behind other geometry.
+    ///     lines.depth_test(true).line(Vec3::ZERO, Vec3::X, 0.0);
+    ///     // Always drawn on top.
+    ///     lines.depth_test(false).line(Vec3::ZERO, Vec3::Y, 0.0);
+    /// }
+    /// ```
+    pub fn depth_test(&mut self, depth_test: bool) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.depth_test = Some(depth_test))
+    }
+
+    /// Draw the lines added through the returned scope with the given dash pattern. Each line
+    /// starts with a dash.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn netcode_debug_system(mut lines: ResMut<DebugLines>) {
+    ///     let (authoritative, predicted) = (Vec3::ZERO, Vec3::new(0.2, 0.0, 0.1));
+    ///     lines.line_colored(authoritative, authoritative + Vec3::X, 0.0, Color::GREEN);
+    ///     lines
+    ///         .dash(LineDash::pixels(6.0, 4.0).with_speed(12.0))
+    ///         .line_colored(predicted, predicted + Vec3::X, 0.0, Color::YELLOW);
+    /// }
+    /// ```
+    pub fn dash(&mut self, dash: LineDash) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.dash = Some(dash))
+    }
+
+    /// Draw the lines added through the returned scope on the given [`RenderLayers`], instead of
+    /// the ones given to the plugin with [`DebugLinesPlugin::with_layers`].
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy::render::view::RenderLayers;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn pathfinding_system(mut lines: ResMut<DebugLines>) {
+    ///     // Only shown by cameras which see layer 3, like a picture-in-picture camera.
+    ///     lines.render_layers(RenderLayers::layer(3)).line(Vec3::ZERO, Vec3::X, 0.0);
+    ///     // Shown by cameras which see layer 0 or layer 3, or both.
+    ///     lines
+    ///         .render_layers(RenderLayers::from_layers(&[0, 3]))
+    ///         .line(Vec3::ZERO, Vec3::Y, 0.0);
+    /// }
+    /// ```
+    pub fn render_layers(&mut self, render_layers: RenderLayers) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.render_layers = Some(render_layers))
+    }
+
+    /// Draw the lines added through the returned scope on a single render layer.
+    ///
+    /// Short for [`DebugLines::render_layers`].
+    pub fn on_layer(&mut self, layer: u8) -> DebugLinesScope<'_> {
+        self.render_layers(RenderLayers::layer(layer))
+    }
+
+    /// Give the points of the lines added through the returned scope in the given [`LineSpace`].
+    pub fn space(&mut self, space: LineSpace) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.space = space)
+    }
+
+    /// Give the points of the lines added through the returned scope in normalized device
+    /// coordinates, see [`LineSpace::Ndc`].
+    ///
+    /// Short for [`DebugLines::space`].
+    pub fn in_ndc(&mut self) -> DebugLinesScope<'_> {
+        self.space(LineSpace::Ndc)
+    }
+
+    /// Give the points of the lines added through the returned scope in pixels from the top left
+    /// of the viewport, see [`LineSpace::Screen`]. [`LineWidth::World`] widths are taken as
+    /// pixels for these lines.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// // Draws a crosshair under the cursor.
+    /// fn crosshair_system(mut lines: ResMut<DebugLines>, windows: Query<&Window>) {
+    ///     let Some(cursor) = windows.single().physical_cursor_position() else {
+    ///         return;
+    ///     };
+    ///     let cursor = cursor.extend(0.0);
+    ///     let mut hud = lines.in_screen();
+    ///     hud.line(cursor - Vec3::X * 10.0, cursor + Vec3::X * 10.0, 0.0);
+    ///     hud.line(cursor - Vec3::Y * 10.0, cursor + Vec3::Y * 10.0, 0.0);
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugLines::space`].
+    pub fn in_screen(&mut self) -> DebugLinesScope<'_> {
+        self.space(LineSpace::Screen)
+    }
+
+    /// Draw the lines added through the returned scope only for the given camera entity.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Player {
+    ///     camera: Entity,
+    /// }
+    ///
+    /// // Each player only sees their own aim line on their half of the screen.
+    /// fn aim_system(mut lines: ResMut<DebugLines>, players: Query<(&Player, &Transform)>) {
+    ///     for (player, transform) in players.iter() {
+    ///         let start = transform.translation;
+    ///         let end = start + transform.forward() * 10.0;
+    ///         lines.for_camera(player.camera).line(start, end, 0.0);
+    ///     }
+    /// }
+    /// ```
+    pub fn for_camera(&mut self, camera: Entity) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.camera = Some(camera))
+    }
+
+    /// Adds the lines added through the returned scope to a named group, which can be toggled or
+    /// recolored at once with [`DebugLines::group_settings_mut`].
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn ai_system(mut lines: ResMut<DebugLines>) {
+    ///     lines.group("ai").line(Vec3::ZERO, Vec3::X, 0.0);
+    /// }
+    ///
+    /// fn toggle_ai_lines(keys: Res<Input<KeyCode>>, mut lines: ResMut<DebugLines>) {
+    ///     if keys.just_pressed(KeyCode::F1) {
+    ///         let ai = lines.group_settings_mut("ai");
+    ///         ai.enabled = !ai.enabled;
+    ///     }
+    /// }
+    /// ```
+    pub fn group(&mut self, name: &'static str) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.group = Some(name))
+    }
+
+    /// Settings of the named group, or `None` if they were never changed.
+    pub fn group_settings(&self, name: &str) -> Option<&LineGroupSettings> {
+        self.groups.get(name)
+    }
+
+    /// Settings of the named group, which can be changed at any time.
+    pub fn group_settings_mut(&mut self, name: &'static str) -> &mut LineGroupSettings {
+        self.groups.entry(name).or_default()
+    }
+
+    /// Number of lines, triangles, texts and points currently in the named group, including those
+    /// which are not drawn because the group is disabled.
+    pub fn group_len(&self, name: &str) -> usize {
+        let in_group = |style: &LineStyle| style.group == Some(name);
+        self.lines.iter().filter(|line| in_group(&line.style)).count()
+            + self.triangles.iter().filter(|triangle| in_group(&triangle.style)).count()
+            + self.texts.iter().filter(|text| in_group(&text.style)).count()
+            + self.points.iter().filter(|point| in_group(&point.style)).count()
+    }
+
+    pub(crate) fn with_style(&mut self, new_style: LineStyle) -> DebugLinesScope<'_> {
+        self.scoped(|style| *style = new_style)
+    }
+
+    // Whether to draw the lines added through the returned scope in the transparent pass, without
+    // writing depth, for lines with translucent colors.
+    pub(crate) fn translucent(&mut self, translucent: bool) -> DebugLinesScope<'_> {
+        self.scoped(|style| style.translucent = translucent)
+    }
+
+    fn scoped(&mut self, change: impl FnOnce(&mut LineStyle)) -> DebugLinesScope<'_> {
+        let previous = self.style;
+        change(&mut self.style);
+        DebugLinesScope { lines: self, previous }
+    }
+
+    /// Draw a line in world space.
     ///
     /// # Arguments
     ///
@@ -413,11 +1425,13 @@ impl DebugLines {
     /// * `end` - The end of the line in world space
     /// * `duration` - Duration (in seconds) that the line should show for -- a value of
     ///   zero will show the line for 1 frame.
-    pub fn line(&mut self, start: Vec3, end: Vec3, duration: f32) {
-        self.line_colored(start, end, duration, Color::WHITE);
+    ///
+    /// Returns the [`LineId`] of the new line.
+    pub fn line(&mut self, start: Vec3, end: Vec3, duration: f32) -> LineId {
+        self.line_colored(start, end, duration, Color::WHITE)
     }
 
-    /// Draw a line in world space with a specified color, or update an existing line
+    /// Draw a line in world space with a specified color.
     ///
     /// # Arguments
     ///
@@ -426,11 +1440,13 @@ impl DebugLines {
     /// * `duration` - Duration (in seconds) that the line should show for -- a value of
     ///   zero will show the line for 1 frame.
     /// * `color` - Line color
-    pub fn line_colored(&mut self, start: Vec3, end: Vec3, duration: f32, color: Color) {
-        self.line_gradient(start, end, duration, color, color);
+    ///
+    /// Returns the [`LineId`] of the new line.
+    pub fn line_colored(&mut self, start: Vec3, end: Vec3, duration: f32, color: Color) -> LineId {
+        self.line_gradient(start, end, duration, color, color)
     }
 
-    /// Draw a line in world space with a specified gradient color, or update an existing line
+    /// Draw a line in world space with a specified gradient color.
     ///
     /// # Arguments
     ///
@@ -440,54 +1456,388 @@ impl DebugLines {
     ///   zero will show the line for 1 frame.
     /// * `start_color` - Line color
     /// * `end_color` - Line color
-    pub fn line_gradient(&mut self, start: Vec3, end: Vec3, duration: f32, start_color: Color, end_color: Color) {
-        if self.positions.len() >= MAX_POINTS {
+    ///
+    /// Returns the [`LineId`] of the new line. If there were already [`DebugLines::max_lines`]
+    /// lines, the line is not added and the id does not refer to any line.
+    pub fn line_gradient(
+        &mut self,
+        start: Vec3,
+        end: Vec3,
+        duration: f32,
+        start_color: Color,
+        end_color: Color,
+    ) -> LineId {
+        if self.lines.len() >= self.max_lines {
             warn!("Tried to add a new line when existing number of lines was already at maximum, ignoring.");
+            return LineId(SlotKey::DANGLING);
+        }
+
+        LineId(self.lines.insert(DebugLine {
+            start,
+            end,
+            start_color: start_color.as_linear_rgba_f32(),
+            end_color: end_color.as_linear_rgba_f32(),
+            duration,
+            style: self.style,
+        }))
+    }
+
+    /// Draw a filled triangle, usually with a translucent color. Triangles are drawn like lines,
+    /// following the settings of the current scope, except for the width and dash pattern.
+    ///
+    /// Filled triangles are drawn after opaque geometry, and batches of them are sorted from back
+    /// to front as a whole. The triangles within a batch are drawn in the order they were added,
+    /// so overlapping ones may not blend in the right order.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn some_system(mut lines: ResMut<DebugLines>) {
+    ///     let color = Color::rgba(1.0, 0.0, 0.0, 0.3);
+    ///     lines.triangle(Vec3::ZERO, Vec3::X, Vec3::Y, 0.0, color);
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `a`, `b`, `c` - The corners of the triangle.
+    /// * `duration` - Duration (in seconds) that the triangle should show for -- a value of zero
+    ///   will show the triangle for 1 frame.
+    /// * `color` - Color of the triangle.
+    pub fn triangle(&mut self, a: Vec3, b: Vec3, c: Vec3, duration: f32, color: Color) {
+        if self.triangles.len() >= self.max_lines {
+            warn!("Tried to add a new triangle when existing number of triangles was already at maximum, ignoring.");
+            return;
+        }
+
+        self.triangles.push(DebugTriangle {
+            points: [a, b, c],
+            color: color.as_linear_rgba_f32(),
+            duration,
+            style: self.style,
+        });
+    }
+
+    /// Draw text anchored at a position, with a built-in font made of lines so no font asset is
+    /// needed. Text follows the settings of the current scope, except for the width and dash
+    /// pattern of the lines.
+    ///
+    /// By default, text is aligned with the screen and 16 pixels high. With a size in world units
+    /// it faces the camera instead, see [`DebugLines::text_size`]. The first line of text starts
+    /// at `position` and sits on it, the next lines go down. Characters which aren't printable
+    /// ASCII are drawn as `?`.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn label_system(mut lines: ResMut<DebugLines>, query: Query<(&Name, &GlobalTransform)>) {
+    ///     for (name, transform) in query.iter() {
+    ///         let position = transform.translation() + Vec3::Y;
+    ///         lines.text(position, name.as_str(), Color::WHITE, 0.0);
+    ///         let below = position - Vec3::Y * 0.5;
+    ///         lines.text_size(TextSize::World(0.2)).text(below, "hp: 100", Color::GREEN, 0.0);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position the text is anchored at.
+    /// * `text` - The text to draw, which can span several lines.
+    /// * `color` - Color of the text.
+    /// * `duration` - Duration (in seconds) that the text should show for -- a value of zero will
+    ///   show the text for 1 frame.
+    pub fn text(&mut self, position: Vec3, text: impl Into<String>, color: Color, duration: f32) {
+        if self.texts.len() >= self.max_lines {
+            warn!("Tried to add new text when existing number of texts was already at maximum, ignoring.");
             return;
         }
 
-        self.positions.push(start.into());
-        self.positions.push(end.into());
-        self.colors.push(start_color.as_linear_rgba_f32());
-        self.colors.push(end_color.as_linear_rgba_f32());
-        self.durations.push(duration);
+        self.texts.push(DebugText {
+            position,
+            text: text.into(),
+            color: color.as_linear_rgba_f32(),
+            duration,
+            style: self.style,
+        });
     }
 
-    // Returns the indices of the start and end positions of the nth line.
-    // The indices can also be used to access color data.
-    fn nth(&self, idx: usize) -> (usize, usize) {
-        let i = idx * 2;
-        (i, i + 1)
+    /// Draw a marker at a point, with the same size on screen regardless of its distance to the
+    /// camera. Points follow the settings of the current scope, except for the width and dash
+    /// pattern of the lines, and are drawn as squares of [`DEFAULT_POINT_SIZE`] pixels unless
+    /// changed with [`DebugLines::point_marker`] and [`DebugLines::point_size`].
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn raycast_system(mut lines: ResMut<DebugLines>) {
+    ///     let hit = Vec3::new(1.0, 0.0, 2.0);
+    ///     lines.point_marker(PointMarker::Cross).point_size(10.0).point(hit, 0.5, Color::RED);
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the point.
+    /// * `duration` - Duration (in seconds) that the point should show for -- a value of zero will
+    ///   show the point for 1 frame.
+    /// * `color` - Color of the marker.
+    pub fn point(&mut self, position: Vec3, duration: f32, color: Color) {
+        if self.points.len() >= self.max_lines {
+            warn!("Tried to add a new point when existing number of points was already at maximum, ignoring.");
+            return;
+        }
+
+        self.points.push(DebugPoint {
+            position,
+            color: color.as_linear_rgba_f32(),
+            duration,
+            style: self.style,
+        });
     }
 
-    // Prepare [`ImmediateLinesStorage`] and [`RetainedLinesStorage`] for next
-    // frame.
-    // This clears the immediate mod buffers and tells the retained mode
-    // buffers to recompute expired lines list.
-    fn update(&mut self, dt: f32) {
-        // TODO: an actual line counter wouldn't hurt.
-        let mut i = 0;
-        let mut len = self.durations.len();
-        while i != len {
-            self.durations[i] -= dt;
-            // <= instead of < is fine here because this is always called AFTER sending the
-            // data to the mesh, so we're guaranteed at least a frame here.
-            if self.durations[i] <= 0.0 {
-                let (cur_s, cur_e) = self.nth(i);
-                let (last_s, last_e) = self.nth(len - 1);
-                self.positions.swap(cur_s, last_s);
-                self.positions.swap(cur_e, last_e);
-                self.colors.swap(cur_s, last_s);
-                self.colors.swap(cur_e, last_e);
-                self.durations.swap(i, len - 1);
-                len -= 1;
+    /// Draw markers at many points, for example a point cloud, see [`DebugLines::point`].
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn point_cloud_system(mut lines: ResMut<DebugLines>) {
+    ///     let cloud = (0..100).map(|i| Vec3::new(i as f32 * 0.1, (i as f32 * 0.1).sin(), 0.0));
+    ///     lines.point_marker(PointMarker::Circle).points(cloud, 0.0, Color::CYAN);
+    /// }
+    /// ```
+    pub fn points(&mut self, positions: impl IntoIterator<Item = Vec3>, duration: f32, color: Color) {
+        for position in positions {
+            self.point(position, duration, color);
+        }
+    }
+
+    /// Moves an existing line.
+    ///
+    /// Returns false if the line has expired or was removed.
+    pub fn move_line(&mut self, id: LineId, start: Vec3, end: Vec3) -> bool {
+        self.lines
+            .get_mut(id.0)
+            .map(|line| {
+                line.start = start;
+                line.end = end;
+            })
+            .is_some()
+    }
+
+    /// Changes the colors of an existing line.
+    ///
+    /// Returns false if the line has expired or was removed.
+    pub fn recolor_line(&mut self, id: LineId, start_color: Color, end_color: Color) -> bool {
+        self.lines
+            .get_mut(id.0)
+            .map(|line| {
+                line.start_color = start_color.as_linear_rgba_f32();
+                line.end_color = end_color.as_linear_rgba_f32();
+            })
+            .is_some()
+    }
+
+    /// Sets how long (in seconds) an existing line should still show for, from now.
+    ///
+    /// Returns false if the line has expired or was removed.
+    pub fn set_line_duration(&mut self, id: LineId, duration: f32) -> bool {
+        self.lines.get_mut(id.0).map(|line| line.duration = duration).is_some()
+    }
+
+    /// Removes a line before it expires.
+    ///
+    /// Returns false if the line has already expired or was removed.
+    pub fn remove_line(&mut self, id: LineId) -> bool {
+        self.lines.remove(id.0).is_some()
+    }
+
+    /// Returns true if the line has not expired or been removed yet.
+    pub fn contains_line(&self, id: LineId) -> bool {
+        self.lines.get(id.0).is_some()
+    }
+
+    /// Number of lines currently drawn.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns true if there are no lines to draw.
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 0
+    }
+
+    // Builds the vertices of the lines and triangles to draw, grouped by batch.
+    fn batches(&self) -> HashMap<LineBatch, LineVertices> {
+        let mut batches: HashMap<LineBatch, LineVertices> = HashMap::default();
+        if !self.enabled {
+            return batches;
+        }
+
+        for line in self.lines.iter() {
+            let group = line.style.group.and_then(|name| self.groups.get(name));
+            if group.is_some_and(|group| !group.enabled) {
+                continue;
+            }
+            let (start_color, end_color) = match group.and_then(|group| group.color) {
+                Some(color) => (color.as_linear_rgba_f32(), color.as_linear_rgba_f32()),
+                None => (line.start_color, line.end_color),
+            };
+
+            // Lines without dashes or gaps are solid.
+            let dash = line.style.dash.filter(|dash| dash.dash > 0.0 && dash.gap > 0.0);
+            let (mode, width) = match (line.style.space, line.style.width) {
+                // Screen space lines have no size in the world.
+                (LineSpace::Ndc | LineSpace::Screen, LineWidth::World(width)) => LineWidth::Pixels(width).mode(),
+                (_, width) => width.mode(),
+            };
+            let batch = LineBatch::new(&line.style, group, mode, dash.map(|dash| dash.units));
+            let vertices = batches.entry(batch).or_default();
+            let dash = dash.map(|dash| {
+                let length = line.start.distance(line.end);
+                let start = match dash.units {
+                    DashUnits::World if length > 0.0 => line.style.dash_offset / length,
+                    _ => 0.0,
+                };
+                [dash.attribute(start), dash.attribute(start + 1.0)]
+            });
+            let (start, end) = (line.start.into(), line.end.into());
+            if mode == LineMode::Thin {
+                vertices.push_thin(start, end, start_color, end_color, dash);
             } else {
-                i += 1;
+                vertices.push_thick(start, end, start_color, end_color, width, dash);
+            }
+        }
+
+        for triangle in &self.triangles {
+            let group = triangle.style.group.and_then(|name| self.groups.get(name));
+            if group.is_some_and(|group| !group.enabled) {
+                continue;
+            }
+            let color = match group.and_then(|group| group.color) {
+                Some(color) => color.as_linear_rgba_f32(),
+                None => triangle.color,
+            };
+
+            let batch = LineBatch::new(&triangle.style, group, LineMode::Filled, None);
+            let vertices = batches.entry(batch).or_default();
+            vertices.positions.extend(triangle.points.map(|point| point.to_array()));
+            vertices.colors.extend([color; 3]);
+        }
+
+        for text in &self.texts {
+            let group = text.style.group.and_then(|name| self.groups.get(name));
+            if group.is_some_and(|group| !group.enabled) {
+                continue;
+            }
+            let color = match group.and_then(|group| group.color) {
+                Some(color) => color.as_linear_rgba_f32(),
+                None => text.color,
+            };
+
+            let mode = match (text.style.space, text.style.text_size) {
+                (LineSpace::World, TextSize::World(_)) => LineMode::WorldText,
+                // Screen space text has no size in the world.
+                _ => LineMode::PixelText,
+            };
+            let batch = LineBatch::new(&text.style, group, mode, None);
+            let vertices = batches.entry(batch).or_default();
+            let size = text.style.text_size.value();
+            for (start, end) in text::segments(&text.text) {
+                vertices.positions.extend([text.position.to_array(); 2]);
+                vertices.colors.extend([color; 2]);
+                vertices.offsets.extend([(start * size).to_array(), (end * size).to_array()]);
+            }
+        }
+
+        for point in &self.points {
+            let group = point.style.group.and_then(|name| self.groups.get(name));
+            if group.is_some_and(|group| !group.enabled) {
+                continue;
+            }
+            let color = match group.and_then(|group| group.color) {
+                Some(color) => color.as_linear_rgba_f32(),
+                None => point.color,
+            };
+
+            let batch = LineBatch::new(&point.style, group, LineMode::Points(point.style.point_marker), None);
+            let vertices = batches.entry(batch).or_default();
+            let half_size = point.style.point_size.unwrap_or(DEFAULT_POINT_SIZE) * 0.5;
+            // Two triangles forming a square around the point.
+            let corners = [[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]];
+            for [x, y] in corners {
+                vertices.positions.push(point.position.to_array());
+                vertices.colors.push(color);
+                vertices.offsets.push([x * half_size, y * half_size]);
             }
         }
+        batches
+    }
+
+    // Removes the lines drawn only for cameras which don't exist anymore.
+    fn remove_camera_lines(&mut self, exists: impl Fn(Entity) -> bool) {
+        let keep = |style: &LineStyle| match style.camera {
+            Some(camera) => exists(camera),
+            None => true,
+        };
+        self.lines.retain(|line| keep(&line.style));
+        self.triangles.retain(|triangle| keep(&triangle.style));
+        self.texts.retain(|text| keep(&text.style));
+        self.points.retain(|point| keep(&point.style));
+    }
+
+    // Counts down the duration of the lines, and removes the expired ones.
+    fn update(&mut self, dt: f32) {
+        self.lines.retain(|line| {
+            line.duration -= dt;
+            // <= instead of < is fine here because this is always called AFTER sending the
+            // data to the mesh, so we're guaranteed at least a frame here.
+            line.duration > 0.0
+        });
+        self.triangles.retain_mut(|triangle| {
+            triangle.duration -= dt;
+            triangle.duration > 0.0
+        });
+        self.texts.retain_mut(|text| {
+            text.duration -= dt;
+            text.duration > 0.0
+        });
+        self.points.retain_mut(|point| {
+            point.duration -= dt;
+            point.duration > 0.0
+        });
+    }
+}
+
+/// Temporarily changes how lines added to [`DebugLines`] are drawn.
+///
+/// Created by [`DebugLines::dimension`] and friends, the previous settings are restored when the
+/// scope is dropped.
+pub struct DebugLinesScope<'a> {
+    lines: &'a mut DebugLines,
+    previous: LineStyle,
+}
+
+impl std::ops::Deref for DebugLinesScope<'_> {
+    type Target = DebugLines;
+
+    fn deref(&self) -> &DebugLines {
+        self.lines
+    }
+}
+
+impl std::ops::DerefMut for DebugLinesScope<'_> {
+    fn deref_mut(&mut self) -> &mut DebugLines {
+        self.lines
+    }
+}
 
-        self.positions.truncate(len * 2);
-        self.colors.truncate(len * 2);
-        self.durations.truncate(len);
+impl Drop for DebugLinesScope<'_> {
+    fn drop(&mut self) {
+        self.lines.style = self.previous;
     }
 }
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+
+use crate::{DebugLines, EntityVisibility, LineDimension, LineStyle, LineWidth};
+
+/// A line in the local space of an entity, see [`DebugLinesLocal`].
+#[derive(Debug, Clone, Copy)]
+pub struct LocalLine {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub start_color: Color,
+    pub end_color: Color,
+}
+
+/// Lines attached to an entity, which follow its [`GlobalTransform`].
+///
+/// The lines are drawn every frame while the entity is visible, so they don't need to be added
+/// again when the entity moves.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn spawn_ship(mut commands: Commands) {
+///     commands.spawn((
+///         SpatialBundle::default(),
+///         DebugLinesLocal::new()
+///             .with_line(Vec3::ZERO, Vec3::Z * -2.0, Color::GREEN)
+///             .with_line(Vec3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Color::RED)
+///             .width(2.0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Default)]
+pub struct DebugLinesLocal {
+    pub lines: Vec<LocalLine>,
+    style: LineStyle,
+}
+
+impl DebugLinesLocal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a line in the local space of the entity.
+    pub fn with_line(self, start: Vec3, end: Vec3, color: Color) -> Self {
+        self.with_gradient(start, end, color, color)
+    }
+
+    /// Adds a line with a gradient color in the local space of the entity.
+    pub fn with_gradient(mut self, start: Vec3, end: Vec3, start_color: Color, end_color: Color) -> Self {
+        self.lines.push(LocalLine {
+            start,
+            end,
+            start_color,
+            end_color,
+        });
+        self
+    }
+
+    /// Sets the width of the lines, either a [`LineWidth`] or a number of pixels.
+    pub fn width(mut self, width: impl Into<LineWidth>) -> Self {
+        self.style.width = width.into();
+        self
+    }
+
+    /// Sets whether the lines are hidden behind other geometry, see [`DebugLines::depth_test`].
+    pub fn depth_test(mut self, depth_test: bool) -> Self {
+        self.style.depth_test = Some(depth_test);
+        self
+    }
+
+    /// Sets the kind of camera the lines are drawn for, see [`DebugLines::dimension`].
+    pub fn dimension(mut self, dimension: LineDimension) -> Self {
+        self.style.dimension = dimension;
+        self
+    }
+
+    /// Adds the lines to a named group, see [`DebugLines::group`]. The group settings can also be
+    /// used to draw the lines on other render layers, or for a single camera.
+    pub fn group(mut self, name: &'static str) -> Self {
+        self.style.group = Some(name);
+        self
+    }
+}
+
+// Draws the lines of the entities with `DebugLinesLocal`, moved to world space.
+pub(crate) fn draw_local_lines(
+    mut lines: ResMut<DebugLines>,
+    visibility: EntityVisibility,
+    entities: Query<(Entity, &DebugLinesLocal, &GlobalTransform)>,
+) {
+    for (_, local, transform) in entities.iter().filter(|(entity, ..)| visibility.is_visible(*entity)) {
+        let mut lines = lines.with_style(local.style);
+        for line in &local.lines {
+            lines.line_gradient(
+                transform.transform_point(line.start),
+                transform.transform_point(line.end),
+                0.0,
+                line.start_color,
+                line.end_color,
+            );
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use bevy::{prelude::*, render::mesh::VertexAttributeValues, sprite::Mesh2dHandle};
+
+use crate::{wireframe::entity_mesh, DebugLines, EntityVisibility};
+
+/// Draws the normal of each vertex of the mesh of the entity, and optionally its tangent.
+///
+/// The lines start at the vertex and have the same length in world space regardless of the scale
+/// of the entity, which makes flipped or broken normals easy to spot.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn spawn_model(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         PbrBundle {
+///             mesh: asset_server.load("model.glb#Mesh0/Primitive0"),
+///             ..default()
+///         },
+///         ShowNormals {
+///             length: 0.05,
+///             tangents: true,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShowNormals {
+    /// Length of the lines in world units.
+    pub length: f32,
+    pub normal_color: Color,
+    /// Whether to also draw the tangents, if the mesh has any.
+    pub tangents: bool,
+    pub tangent_color: Color,
+}
+
+impl Default for ShowNormals {
+    fn default() -> Self {
+        Self {
+            length: 0.1,
+            normal_color: Color::BLUE,
+            tangents: false,
+            tangent_color: Color::RED,
+        }
+    }
+}
+
+// Draws the vertex normals of the entities with `ShowNormals`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn draw_normals(
+    mut lines: ResMut<DebugLines>,
+    meshes: Res<Assets<Mesh>>,
+    visibility: EntityVisibility,
+    entities: Query<(
+        Entity,
+        &ShowNormals,
+        Option<&Handle<Mesh>>,
+        Option<&Mesh2dHandle>,
+        &GlobalTransform,
+    )>,
+) {
+    let visible = entities.iter().filter(|(entity, ..)| visibility.is_visible(*entity));
+    for (_, show_normals, mesh_3d, mesh_2d, transform) in visible {
+        let Some((id, dimension)) = entity_mesh(mesh_3d, mesh_2d) else {
+            continue;
+        };
+        let Some(mesh) = meshes.get(id) else {
+            continue;
+        };
+        let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION).and_then(|p| p.as_float3()) else {
+            continue;
+        };
+
+        let matrix = Mat3::from(transform.affine().matrix3);
+        // Normals are transformed by the inverse transpose, so they stay perpendicular to the
+        // surface when the entity is scaled unevenly.
+        let normal_matrix = matrix.inverse().transpose();
+        let mut lines = lines.dimension(dimension);
+
+        if let Some(normals) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).and_then(|n| n.as_float3()) {
+            for (&position, &normal) in positions.iter().zip(normals) {
+                let start = transform.transform_point(position.into());
+                let direction = (normal_matrix * Vec3::from(normal)).normalize_or_zero();
+                let end = start + direction * show_normals.length;
+                lines.line_colored(start, end, 0.0, show_normals.normal_color);
+            }
+        }
+
+        if !show_normals.tangents {
+            continue;
+        }
+        if let Some(VertexAttributeValues::Float32x4(tangents)) = mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+            for (&position, &tangent) in positions.iter().zip(tangents) {
+                let start = transform.transform_point(position.into());
+                let direction = (matrix * Vec3::from_slice(&tangent[..3])).normalize_or_zero();
+                let end = start + direction * show_normals.length;
+                lines.line_colored(start, end, 0.0, show_normals.tangent_color);
+            }
+        }
+    }
+}
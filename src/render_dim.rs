@@ -1,6 +1,83 @@
+use bevy::render::{
+    mesh::{Mesh, VertexAttributeDescriptor},
+    render_resource::ShaderDefVal,
+};
+
+use crate::{
+    DashUnits, LineMode, LineSpace, PointMarker, ATTRIBUTE_LINE_DASH, ATTRIBUTE_LINE_OTHER, ATTRIBUTE_LINE_PARAMS,
+    ATTRIBUTE_ANCHOR_OFFSET,
+};
+
+// Vertex attributes used by the shaders for each line mode.
+fn vertex_attributes(mode: LineMode, dash: Option<DashUnits>) -> Vec<VertexAttributeDescriptor> {
+    let mut attributes = vec![
+        Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+        Mesh::ATTRIBUTE_COLOR.at_shader_location(1),
+    ];
+    if mode.is_thick() || dash.is_some() {
+        attributes.push(ATTRIBUTE_LINE_OTHER.at_shader_location(2));
+    }
+    if mode.is_thick() {
+        attributes.push(ATTRIBUTE_LINE_PARAMS.at_shader_location(3));
+    }
+    if dash.is_some() {
+        attributes.push(ATTRIBUTE_LINE_DASH.at_shader_location(4));
+    }
+    if mode.is_anchored() {
+        attributes.push(ATTRIBUTE_ANCHOR_OFFSET.at_shader_location(5));
+    }
+    attributes
+}
+
+fn push_mode_shader_defs(
+    mode: LineMode,
+    space: LineSpace,
+    dash: Option<DashUnits>,
+    shader_defs: &mut Vec<ShaderDefVal>,
+) {
+    if mode.is_thick() || dash.is_some() {
+        shader_defs.push("LINES_OTHER".into());
+    }
+    match dash {
+        None => {},
+        Some(DashUnits::World) => shader_defs.push("LINES_DASHED".into()),
+        Some(DashUnits::Pixels) => {
+            shader_defs.push("LINES_DASHED".into());
+            shader_defs.push("LINES_DASH_PIXELS".into());
+        },
+    }
+    match mode {
+        LineMode::Thin | LineMode::Filled => {},
+        LineMode::Pixels => shader_defs.push("LINES_THICK".into()),
+        LineMode::World => {
+            shader_defs.push("LINES_THICK".into());
+            shader_defs.push("LINES_WORLD_WIDTH".into());
+        },
+        LineMode::PixelText => shader_defs.push("LINES_ANCHORED".into()),
+        LineMode::WorldText => {
+            shader_defs.push("LINES_ANCHORED".into());
+            shader_defs.push("LINES_ANCHORED_WORLD".into());
+        },
+        LineMode::Points(marker) => {
+            shader_defs.push("LINES_ANCHORED".into());
+            shader_defs.push("LINES_POINTS".into());
+            match marker {
+                PointMarker::Square => {},
+                PointMarker::Circle => shader_defs.push("POINTS_CIRCLE".into()),
+                PointMarker::Cross => shader_defs.push("POINTS_CROSS".into()),
+            }
+        },
+    }
+    match space {
+        LineSpace::World => {},
+        LineSpace::Ndc => shader_defs.push("LINES_SPACE_NDC".into()),
+        LineSpace::Screen => shader_defs.push("LINES_SPACE_SCREEN".into()),
+    }
+}
+
 pub mod r3d {
     use bevy::{
-        core_pipeline::core_3d::Opaque3d,
+        core_pipeline::core_3d::{Opaque3d, Transparent3d},
         pbr::{
             DrawMesh, MeshPipeline, MeshPipelineKey, MeshPipelineViewLayoutKey, RenderMeshInstances, SetMeshBindGroup,
             SetMeshViewBindGroup, MAX_CASCADES_PER_LIGHT, MAX_DIRECTIONAL_LIGHTS,
@@ -13,16 +90,15 @@ pub mod r3d {
             render_resource::{
                 BlendState, ColorTargetState, ColorWrites, CompareFunction, DepthBiasState, DepthStencilState,
                 FragmentState, FrontFace, MultisampleState, PipelineCache, PolygonMode, PrimitiveState,
-                PrimitiveTopology, RenderPipelineDescriptor, ShaderDefVal, SpecializedMeshPipeline,
-                SpecializedMeshPipelineError, SpecializedMeshPipelines, StencilFaceState, StencilState, TextureFormat,
-                VertexState,
+                RenderPipelineDescriptor, ShaderDefVal, SpecializedMeshPipeline, SpecializedMeshPipelineError,
+                SpecializedMeshPipelines, StencilFaceState, StencilState, TextureFormat, VertexState,
             },
             texture::BevyDefault,
-            view::{ExtractedView, Msaa, ViewTarget},
+            view::{ExtractedView, Msaa, ViewTarget, VisibleEntities},
         },
     };
 
-    use crate::{DebugLinesConfig, RenderDebugLinesMesh, DEBUG_LINES_SHADER_HANDLE};
+    use crate::{DashUnits, DebugLinesSettings, LineMode, LineSpace, RenderDebugLinesMesh, DEBUG_LINES_SHADER_HANDLE};
 
     #[derive(Resource)]
     pub(crate) struct DebugLinePipeline {
@@ -39,11 +115,11 @@ pub mod r3d {
     }
 
     impl SpecializedMeshPipeline for DebugLinePipeline {
-        type Key = (bool, MeshPipelineKey);
+        type Key = (bool, LineMode, LineSpace, Option<DashUnits>, MeshPipelineKey);
 
         fn specialize(
             &self,
-            (depth_test, key): Self::Key,
+            (depth_test, mode, space, dash, key): Self::Key,
             layout: &MeshVertexBufferLayout,
         ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
             let mut shader_defs = Vec::new();
@@ -59,6 +135,7 @@ pub mod r3d {
             if depth_test {
                 shader_defs.push("DEPTH_TEST_ENABLED".into());
             }
+            super::push_mode_shader_defs(mode, space, dash, &mut shader_defs);
 
             let (label, blend, depth_write_enabled);
             if key.contains(MeshPipelineKey::BLEND_ALPHA) {
@@ -76,10 +153,7 @@ pub mod r3d {
                 depth_write_enabled = true;
             }
 
-            let vertex_buffer_layout = layout.get_layout(&[
-                Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
-                Mesh::ATTRIBUTE_COLOR.at_shader_location(1),
-            ])?;
+            let vertex_buffer_layout = layout.get_layout(&super::vertex_attributes(mode, dash))?;
 
             let bind_group_layout = match key.msaa_samples() {
                 1 => vec![self
@@ -125,7 +199,7 @@ pub mod r3d {
                     unclipped_depth: false,
                     polygon_mode: PolygonMode::Fill,
                     conservative: false,
-                    topology: PrimitiveTopology::LineList,
+                    topology: mode.topology(),
                     strip_index_format: None,
                 },
                 depth_stencil: Some(DepthStencilState {
@@ -155,49 +229,85 @@ pub mod r3d {
         }
     }
 
-    #[allow(unused)]
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub(crate) fn queue(
         opaque_3d_draw_functions: Res<DrawFunctions<Opaque3d>>,
+        transparent_3d_draw_functions: Res<DrawFunctions<Transparent3d>>,
         debug_line_pipeline: Res<DebugLinePipeline>,
         mut pipelines: ResMut<SpecializedMeshPipelines<DebugLinePipeline>>,
         pipeline_cache: Res<PipelineCache>,
         render_meshes: Res<RenderAssets<Mesh>>,
         msaa: Res<Msaa>,
         render_mesh_instances: Res<RenderMeshInstances>,
-        instance_entities: Query<Entity, With<RenderDebugLinesMesh>>,
-        config: Res<DebugLinesConfig>,
-        mut views: Query<(&ExtractedView, &mut RenderPhase<Opaque3d>)>,
+        instance_entities: Query<&RenderDebugLinesMesh>,
+        settings: Res<DebugLinesSettings>,
+        mut views: Query<(
+            Entity,
+            &ExtractedView,
+            &VisibleEntities,
+            &mut RenderPhase<Opaque3d>,
+            &mut RenderPhase<Transparent3d>,
+        )>,
     ) {
-        let draw_custom = opaque_3d_draw_functions.read().get_id::<DrawDebugLines>().unwrap();
+        let draw_opaque = opaque_3d_draw_functions.read().get_id::<DrawDebugLines>().unwrap();
+        let draw_transparent = transparent_3d_draw_functions.read().get_id::<DrawDebugLines>().unwrap();
         let msaa_key = MeshPipelineKey::from_msaa_samples(msaa.samples());
-        for (view, mut transparent_phase) in views.iter_mut() {
+        for (view_entity, view, visible_entities, mut opaque_phase, mut transparent_phase) in views.iter_mut() {
             let view_matrix = view.transform.compute_matrix();
             let view_row_2 = view_matrix.row(2);
-            for (entity) in instance_entities.iter() {
+            let rangefinder = view.rangefinder3d();
+            for &entity in &visible_entities.entities {
+                let Ok(debug_lines_mesh) = instance_entities.get(entity) else {
+                    continue;
+                };
+                if !debug_lines_mesh.is_drawn_for(view_entity) {
+                    continue;
+                }
                 if let Some(render_mesh_instance) = render_mesh_instances.get(&entity) {
                     if let Some(mesh) = render_meshes.get(render_mesh_instance.mesh_asset_id) {
-                        let mesh_key = msaa_key
-                            | MeshPipelineKey::from_primitive_topology(PrimitiveTopology::LineList)
+                        let translucent = debug_lines_mesh.0.is_translucent();
+                        let mut mesh_key = msaa_key
+                            | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology)
                             | MeshPipelineKey::from_hdr(view.hdr);
+                        if translucent {
+                            mesh_key |= MeshPipelineKey::BLEND_ALPHA;
+                        }
+                        let depth_test = debug_lines_mesh.0.depth_test.unwrap_or(settings.depth_test);
                         let pipeline = pipelines
                             .specialize(
                                 &pipeline_cache,
                                 &debug_line_pipeline,
-                                (config.depth_test, mesh_key),
+                                (
+                                    depth_test,
+                                    debug_lines_mesh.0.mode,
+                                    debug_lines_mesh.0.space,
+                                    debug_lines_mesh.0.dash,
+                                    mesh_key,
+                                ),
                                 &mesh.layout,
                             )
                             .unwrap();
 
-                        let transform = render_mesh_instance.transforms.transform.translation.extend(1.0);
-
-                        transparent_phase.add(Opaque3d {
-                            entity,
-                            pipeline,
-                            draw_function: draw_custom,
-                            distance: view_row_2.dot(transform),
-                            batch_range: 0..0,
-                            dynamic_offset: None,
-                        });
+                        if translucent {
+                            transparent_phase.add(Transparent3d {
+                                entity,
+                                pipeline,
+                                draw_function: draw_transparent,
+                                distance: rangefinder.distance_translation(&debug_lines_mesh.1),
+                                batch_range: 0..0,
+                                dynamic_offset: None,
+                            });
+                        } else {
+                            let transform = render_mesh_instance.transforms.transform.translation.extend(1.0);
+                            opaque_phase.add(Opaque3d {
+                                entity,
+                                pipeline,
+                                draw_function: draw_opaque,
+                                distance: view_row_2.dot(transform),
+                                batch_range: 0..0,
+                                dynamic_offset: None,
+                            });
+                        }
                     }
                 }
             }
@@ -218,9 +328,8 @@ pub mod r2d {
             render_phase::{DrawFunctions, RenderPhase, SetItemPipeline},
             render_resource::{
                 BlendState, ColorTargetState, ColorWrites, FragmentState, FrontFace, MultisampleState, PipelineCache,
-                PolygonMode, PrimitiveState, PrimitiveTopology, RenderPipelineDescriptor, Shader,
-                SpecializedMeshPipeline, SpecializedMeshPipelineError, SpecializedMeshPipelines, TextureFormat,
-                VertexState,
+                PolygonMode, PrimitiveState, RenderPipelineDescriptor, Shader, SpecializedMeshPipeline,
+                SpecializedMeshPipelineError, SpecializedMeshPipelines, TextureFormat, VertexState,
             },
             texture::BevyDefault,
             view::{ExtractedView, Msaa, ViewTarget, VisibleEntities},
@@ -232,7 +341,7 @@ pub mod r2d {
         utils::FloatOrd,
     };
 
-    use crate::{RenderDebugLinesMesh, DEBUG_LINES_SHADER_HANDLE};
+    use crate::{DashUnits, LineMode, LineSpace, RenderDebugLinesMesh, DEBUG_LINES_2D_SHADER_HANDLE};
 
     #[derive(Resource)]
     pub(crate) struct DebugLinePipeline {
@@ -243,34 +352,34 @@ pub mod r2d {
         fn from_world(render_world: &mut World) -> Self {
             DebugLinePipeline {
                 mesh_pipeline: Mesh2dPipeline::from_world(render_world),
-                shader: DEBUG_LINES_SHADER_HANDLE,
+                shader: DEBUG_LINES_2D_SHADER_HANDLE,
             }
         }
     }
 
     impl SpecializedMeshPipeline for DebugLinePipeline {
-        type Key = Mesh2dPipelineKey;
+        type Key = (LineMode, LineSpace, Option<DashUnits>, Mesh2dPipelineKey);
 
         fn specialize(
             &self,
-            key: Self::Key,
+            (mode, space, dash, key): Self::Key,
             layout: &MeshVertexBufferLayout,
         ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
-            let vertex_buffer_layout = layout.get_layout(&[
-                Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
-                Mesh::ATTRIBUTE_COLOR.at_shader_location(1),
-            ])?;
+            let mut shader_defs = Vec::new();
+            super::push_mode_shader_defs(mode, space, dash, &mut shader_defs);
+
+            let vertex_buffer_layout = layout.get_layout(&super::vertex_attributes(mode, dash))?;
 
             Ok(RenderPipelineDescriptor {
                 vertex: VertexState {
                     shader: self.shader.clone_weak(),
                     entry_point: "vertex".into(),
-                    shader_defs: vec![],
+                    shader_defs: shader_defs.clone(),
                     buffers: vec![vertex_buffer_layout],
                 },
                 fragment: Some(FragmentState {
                     shader: self.shader.clone_weak(),
-                    shader_defs: vec![],
+                    shader_defs,
                     entry_point: "fragment".into(),
                     targets: vec![Some(ColorTargetState {
                         format: if key.contains(Mesh2dPipelineKey::HDR) {
@@ -289,7 +398,7 @@ pub mod r2d {
                     unclipped_depth: false,
                     polygon_mode: PolygonMode::Fill,
                     conservative: false,
-                    topology: PrimitiveTopology::LineList,
+                    topology: mode.topology(),
                     strip_index_format: None,
                 },
                 depth_stencil: None,
@@ -304,7 +413,7 @@ pub mod r2d {
         }
     }
 
-    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn queue(
         draw2d_functions: Res<DrawFunctions<Transparent2d>>,
         debug_line_pipeline: Res<DebugLinePipeline>,
@@ -313,21 +422,37 @@ pub mod r2d {
         render_meshes: Res<RenderAssets<Mesh>>,
         msaa: Res<Msaa>,
         render_mesh_instances: Res<RenderMesh2dInstances>,
-        instance_entities: Query<Entity, With<RenderDebugLinesMesh>>,
-        mut views: Query<(&ExtractedView, &VisibleEntities, &mut RenderPhase<Transparent2d>)>,
+        instance_entities: Query<&RenderDebugLinesMesh>,
+        mut views: Query<(Entity, &ExtractedView, &VisibleEntities, &mut RenderPhase<Transparent2d>)>,
     ) {
-        for (view, visible_entities, mut phase) in views.iter_mut() {
+        for (view_entity, view, visible_entities, mut phase) in views.iter_mut() {
             let draw_mesh2d = draw2d_functions.read().get_id::<DrawDebugLines>().unwrap();
             let msaa_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples());
 
             for visible_entity in &visible_entities.entities {
-                if let Some(render_mesh_instance) = render_mesh_instances.get(*&visible_entity) {
+                let Ok(debug_lines_mesh) = instance_entities.get(*visible_entity) else {
+                    continue;
+                };
+                if !debug_lines_mesh.is_drawn_for(view_entity) {
+                    continue;
+                }
+                if let Some(render_mesh_instance) = render_mesh_instances.get(visible_entity) {
                     if let Some(mesh) = render_meshes.get(render_mesh_instance.mesh_asset_id) {
                         let mesh_key = msaa_key
-                            | Mesh2dPipelineKey::from_primitive_topology(PrimitiveTopology::LineList)
+                            | Mesh2dPipelineKey::from_primitive_topology(mesh.primitive_topology)
                             | Mesh2dPipelineKey::from_hdr(view.hdr);
                         let pipeline = specialized_pipelines
-                            .specialize(&pipeline_cache, &debug_line_pipeline, mesh_key, &mesh.layout)
+                            .specialize(
+                                &pipeline_cache,
+                                &debug_line_pipeline,
+                                (
+                                    debug_lines_mesh.0.mode,
+                                    debug_lines_mesh.0.space,
+                                    debug_lines_mesh.0.dash,
+                                    mesh_key,
+                                ),
+                                &mesh.layout,
+                            )
                             .unwrap();
                         phase.add(Transparent2d {
                             entity: *visible_entity,
@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+
+use super::{AddLines, Shape, ShapeHandle};
+
+/// A line with an arrowhead at its end.
+///
+/// The head is made of `fins` lines around the end of the arrow. The first fin points towards the
+/// `up` vector, or lies in the plane facing the camera when [`Arrow::facing`] is set.
+pub struct Arrow {
+    pub start: Vec3,
+    pub end: Vec3,
+    /// Length of the head along the arrow, clamped to the length of the arrow.
+    pub head_length: f32,
+    /// Angle between the fins and the arrow, in radians.
+    pub head_angle: f32,
+    pub fins: u32,
+    pub up: Vec3,
+    /// Position of the camera the head should face, if any.
+    pub facing: Option<Vec3>,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl Arrow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Direction of the first fin, perpendicular to the arrow.
+    fn fin_side(&self, direction: Vec3) -> Vec3 {
+        if let Some(camera) = self.facing {
+            let side = direction.cross(camera - self.end);
+            if side.length_squared() > 1e-6 {
+                return side.normalize();
+            }
+        }
+        // Fall back to other axes when the up vector is parallel to the arrow.
+        [self.up, Vec3::X, Vec3::Y]
+            .into_iter()
+            .map(|up| up.reject_from(direction))
+            .find(|side| side.length_squared() > 1e-6)
+            .unwrap_or(Vec3::Z)
+            .normalize()
+    }
+}
+
+impl Default for Arrow {
+    fn default() -> Self {
+        Self {
+            start: Vec3::ZERO,
+            end: Vec3::X,
+            head_length: 0.2,
+            head_angle: 25f32.to_radians(),
+            fins: 4,
+            up: Vec3::Y,
+            facing: None,
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Arrow> for Shape {
+    fn from(arrow: Arrow) -> Self {
+        Shape::Arrow(arrow)
+    }
+}
+
+impl AddLines for Arrow {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        lines.line_colored(self.start, self.end, self.duration, self.color);
+
+        let Some(direction) = (self.end - self.start).try_normalize() else {
+            return;
+        };
+        let head_length = self.head_length.min(self.start.distance(self.end));
+        let back = self.end - direction * head_length;
+        let radius = head_length * self.head_angle.tan();
+        let side = self.fin_side(direction);
+        for i in 0..self.fins {
+            let angle = i as f32 * std::f32::consts::TAU / self.fins as f32;
+            let fin = Quat::from_axis_angle(direction, angle) * side;
+            lines.line_colored(self.end, back + fin * radius, self.duration, self.color);
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Arrow> {
+    pub fn start(self, start: Vec3) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.start = start;
+        }
+        self
+    }
+
+    pub fn end(self, end: Vec3) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.end = end;
+        }
+        self
+    }
+
+    /// Sets the start of the arrow, and its end relative to the start.
+    pub fn vector(self, start: Vec3, vector: Vec3) -> Self {
+        self.start(start).end(start + vector)
+    }
+
+    pub fn head_length(self, head_length: f32) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.head_length = head_length;
+        }
+        self
+    }
+
+    /// Sets the angle between the fins and the arrow, in radians.
+    pub fn head_angle(self, head_angle: f32) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.head_angle = head_angle;
+        }
+        self
+    }
+
+    pub fn fins(self, fins: u32) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.fins = fins;
+        }
+        self
+    }
+
+    /// Sets the direction the first fin points towards.
+    pub fn up(self, up: Vec3) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.up = up;
+        }
+        self
+    }
+
+    /// Orients the fins around the plane facing the camera at the given position, so that a head
+    /// with 2 fins is always seen flat.
+    pub fn facing(self, camera: Vec3) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.facing = Some(camera);
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Arrow(arrow) = &mut self.shapes.shapes[self.index] {
+            arrow.duration = duration;
+        }
+        self
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use super::{AddLines, Shape, ShapeHandle};
+use crate::{DebugShapes, EntityVisibility};
+
+/// X, Y and Z axes drawn in red, green and blue.
+pub struct Axes {
+    pub position: Vec3,
+    pub rotation: Quat,
+    /// Scales the length of each axis.
+    pub scale: Vec3,
+    pub length: f32,
+    pub arrowheads: bool,
+    pub duration: f32,
+}
+
+impl Axes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Axes {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+            length: 1.0,
+            arrowheads: false,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Axes> for Shape {
+    fn from(axes: Axes) -> Self {
+        Shape::Axes(axes)
+    }
+}
+
+impl AddLines for Axes {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let axes = [
+            (self.rotation * Vec3::X, self.scale.x, Color::RED),
+            (self.rotation * Vec3::Y, self.scale.y, Color::GREEN),
+            (self.rotation * Vec3::Z, self.scale.z, Color::BLUE),
+        ];
+        for (i, &(direction, scale, color)) in axes.iter().enumerate() {
+            let length = self.length * scale;
+            let end = self.position + direction * length;
+            lines.line_colored(self.position, end, self.duration, color);
+
+            if self.arrowheads {
+                // The fins of the head point towards the other two axes.
+                let head_length = length * 0.15;
+                let back = end - direction * head_length;
+                for (j, &(other, _, _)) in axes.iter().enumerate() {
+                    if i != j {
+                        lines.line_colored(end, back + other * head_length * 0.5, self.duration, color);
+                        lines.line_colored(end, back - other * head_length * 0.5, self.duration, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Axes> {
+    pub fn position(self, position: Vec3) -> Self {
+        if let Shape::Axes(axes) = &mut self.shapes.shapes[self.index] {
+            axes.position = position;
+        }
+        self
+    }
+
+    pub fn rotation(self, rotation: Quat) -> Self {
+        if let Shape::Axes(axes) = &mut self.shapes.shapes[self.index] {
+            axes.rotation = rotation;
+        }
+        self
+    }
+
+    pub fn scale(self, scale: Vec3) -> Self {
+        if let Shape::Axes(axes) = &mut self.shapes.shapes[self.index] {
+            axes.scale = scale;
+        }
+        self
+    }
+
+    /// Sets the position, rotation and scale of the axes.
+    pub fn transform(self, transform: &Transform) -> Self {
+        self.position(transform.translation)
+            .rotation(transform.rotation)
+            .scale(transform.scale)
+    }
+
+    /// Sets the position, rotation and scale of the axes.
+    pub fn global_transform(self, transform: &GlobalTransform) -> Self {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        self.position(translation).rotation(rotation).scale(scale)
+    }
+
+    pub fn length(self, length: f32) -> Self {
+        if let Shape::Axes(axes) = &mut self.shapes.shapes[self.index] {
+            axes.length = length;
+        }
+        self
+    }
+
+    pub fn arrowheads(self, arrowheads: bool) -> Self {
+        if let Shape::Axes(axes) = &mut self.shapes.shapes[self.index] {
+            axes.arrowheads = arrowheads;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Axes(axes) = &mut self.shapes.shapes[self.index] {
+            axes.duration = duration;
+        }
+        self
+    }
+}
+
+/// Draws the [`Axes`] of the entity, following its [`GlobalTransform`].
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn spawn_ship(mut commands: Commands) {
+///     commands.spawn((
+///         SpatialBundle::default(),
+///         ShowAxes {
+///             length: 2.0,
+///             arrowheads: true,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShowAxes {
+    pub length: f32,
+    pub arrowheads: bool,
+}
+
+impl Default for ShowAxes {
+    fn default() -> Self {
+        Self {
+            length: 1.0,
+            arrowheads: false,
+        }
+    }
+}
+
+// Draws the axes of the entities with `ShowAxes`.
+pub(crate) fn draw_axes(
+    mut shapes: ResMut<DebugShapes>,
+    visibility: EntityVisibility,
+    entities: Query<(Entity, &ShowAxes, &GlobalTransform)>,
+) {
+    for (_, show_axes, transform) in entities.iter().filter(|(entity, ..)| visibility.is_visible(*entity)) {
+        shapes
+            .axes()
+            .global_transform(transform)
+            .length(show_axes.length)
+            .arrowheads(show_axes.arrowheads);
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+
+use super::{AddLines, CurveControls, Polyline, Shape, ShapeHandle, Subdivision};
+
+/// A Bezier curve, quadratic with 3 control points or cubic with 4.
+///
+/// The curve starts at the first control point and ends at the last one.
+pub struct Bezier {
+    pub points: Vec<Vec3>,
+    pub subdivision: Subdivision,
+    pub controls: CurveControls,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl Bezier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Point of the curve at `t` between 0 and 1, with De Casteljau's algorithm so any number of
+    // control points works.
+    fn point(&self, t: f32) -> Vec3 {
+        let mut points = self.points.clone();
+        for len in (1..points.len()).rev() {
+            for i in 0..len {
+                points[i] = points[i].lerp(points[i + 1], t);
+            }
+        }
+        points[0]
+    }
+}
+
+impl Default for Bezier {
+    fn default() -> Self {
+        Self {
+            points: vec![],
+            subdivision: Subdivision::default(),
+            controls: CurveControls::default(),
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Bezier> for Shape {
+    fn from(bezier: Bezier) -> Self {
+        Shape::Bezier(bezier)
+    }
+}
+
+impl AddLines for Bezier {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        self.controls.add_lines(lines, &self.points, false, self.duration);
+        if self.points.len() < 2 {
+            return;
+        }
+
+        Polyline {
+            points: self.subdivision.points(|t| self.point(t)),
+            color: self.color,
+            duration: self.duration,
+            ..default()
+        }
+        .add_lines(lines);
+    }
+}
+
+impl<'a> ShapeHandle<'a, Bezier> {
+    /// Sets the control points of a quadratic curve.
+    pub fn quadratic(self, start: Vec3, control: Vec3, end: Vec3) -> Self {
+        self.points([start, control, end])
+    }
+
+    /// Sets the control points of a cubic curve.
+    pub fn cubic(self, start: Vec3, control_1: Vec3, control_2: Vec3, end: Vec3) -> Self {
+        self.points([start, control_1, control_2, end])
+    }
+
+    pub fn points(self, points: impl IntoIterator<Item = Vec3>) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.points = points.into_iter().collect();
+        }
+        self
+    }
+
+    /// Splits the curve into the given number of lines.
+    pub fn segments(self, segments: u32) -> Self {
+        self.subdivision(Subdivision::Fixed(segments))
+    }
+
+    /// Splits the curve into lines no further than `tolerance` from it.
+    pub fn adaptive(self, tolerance: f32) -> Self {
+        self.subdivision(Subdivision::Adaptive { tolerance })
+    }
+
+    pub fn subdivision(self, subdivision: Subdivision) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.subdivision = subdivision;
+        }
+        self
+    }
+
+    /// Draws the lines between the control points.
+    pub fn control_polygon(self, control_polygon: bool) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.controls.polygon = control_polygon;
+        }
+        self
+    }
+
+    /// Draws a small cross on each control point.
+    pub fn control_points(self, control_points: bool) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.controls.points = control_points;
+        }
+        self
+    }
+
+    pub fn control_color(self, color: Color) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.controls.color = color;
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Bezier(bezier) = &mut self.shapes.shapes[self.index] {
+            bezier.duration = duration;
+        }
+        self
+    }
+}
@@ -0,0 +1,131 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use bevy::prelude::*;
+
+use super::{AddLines, Circle, Shape, ShapeHandle};
+
+/// A capsule standing along its local Y axis, centered on its position.
+pub struct Capsule {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub radius: f32,
+    /// Distance between the centers of the two half spheres, without the radius of the caps.
+    pub height: f32,
+    pub segments: u32,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl Capsule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A ring of the capsule, `height` above its center.
+    fn ring(&self, height: f32) -> Circle {
+        Circle {
+            radius: self.radius,
+            segments: self.segments,
+            color: self.color,
+            duration: self.duration,
+            ..Circle::ring(self.position, self.rotation, height)
+        }
+    }
+}
+
+impl Default for Capsule {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            radius: 0.5,
+            height: 1.0,
+            segments: 16,
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Capsule> for Shape {
+    fn from(capsule: Capsule) -> Self {
+        Shape::Capsule(capsule)
+    }
+}
+
+impl AddLines for Capsule {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let bottom = self.ring(-self.height * 0.5);
+        let top = self.ring(self.height * 0.5);
+        bottom.add_lines(lines);
+        top.add_lines(lines);
+
+        for i in 0..4 {
+            let angle = i as f32 * FRAC_PI_2;
+            lines.line_colored(bottom.point(angle), top.point(angle), self.duration, self.color);
+        }
+
+        // Two half circles across each cap, in the XY and ZY planes.
+        for rotation in [self.rotation, self.rotation.mul_quat(Quat::from_rotation_y(FRAC_PI_2))] {
+            for (center, start_angle) in [(top.position, 0.0), (bottom.position, PI)] {
+                Circle {
+                    position: center,
+                    rotation,
+                    ..self.ring(0.0)
+                }
+                .add_arc(lines, start_angle, start_angle + PI);
+            }
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Capsule> {
+    pub fn position(self, position: Vec3) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.position = position;
+        }
+        self
+    }
+
+    pub fn rotation(self, rotation: Quat) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.rotation = rotation;
+        }
+        self
+    }
+
+    pub fn radius(self, radius: f32) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.radius = radius;
+        }
+        self
+    }
+
+    pub fn height(self, height: f32) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.height = height;
+        }
+        self
+    }
+
+    pub fn segments(self, segments: u32) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.segments = segments;
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Capsule(capsule) = &mut self.shapes.shapes[self.index] {
+            capsule.duration = duration;
+        }
+        self
+    }
+}
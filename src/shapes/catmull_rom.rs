@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+
+use super::{AddLines, CurveControls, Polyline, Shape, ShapeHandle, Subdivision};
+
+/// A Catmull-Rom spline, a smooth curve going through all of its points.
+pub struct CatmullRom {
+    pub points: Vec<Vec3>,
+    /// Whether the curve loops back to the first point.
+    pub closed: bool,
+    /// How each span between two points is split into lines.
+    pub subdivision: Subdivision,
+    pub controls: CurveControls,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl CatmullRom {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Point of the span starting at point `i`, at `t` between 0 and 1.
+    fn point(&self, i: usize, t: f32) -> Vec3 {
+        let count = self.points.len();
+        let p1 = self.points[i];
+        let p2 = self.points[(i + 1) % count];
+        // The ends of an open curve have no neighbour, mirror the next point instead.
+        let p0 = match (i, self.closed) {
+            (0, false) => 2.0 * p1 - p2,
+            _ => self.points[(i + count - 1) % count],
+        };
+        let p3 = match (i + 2 >= count, self.closed) {
+            (true, false) => 2.0 * p2 - p1,
+            _ => self.points[(i + 2) % count],
+        };
+
+        let (t2, t3) = (t * t, t * t * t);
+        0.5 * (2.0 * p1
+            + (p2 - p0) * t
+            + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+            + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+    }
+}
+
+impl Default for CatmullRom {
+    fn default() -> Self {
+        Self {
+            points: vec![],
+            closed: false,
+            subdivision: Subdivision::Fixed(8),
+            controls: CurveControls::default(),
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<CatmullRom> for Shape {
+    fn from(catmull_rom: CatmullRom) -> Self {
+        Shape::CatmullRom(catmull_rom)
+    }
+}
+
+impl AddLines for CatmullRom {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        self.controls.add_lines(lines, &self.points, self.closed, self.duration);
+
+        let count = self.points.len();
+        let spans = match count {
+            0 | 1 => 0,
+            2 => 1,
+            _ if self.closed => count,
+            _ => count - 1,
+        };
+        let mut points = vec![];
+        for i in 0..spans {
+            let span = self.subdivision.points(|t| self.point(i, t));
+            // Each span starts where the previous one ended.
+            let skip = if i == 0 { 0 } else { 1 };
+            points.extend(span.into_iter().skip(skip));
+        }
+
+        Polyline {
+            points,
+            color: self.color,
+            duration: self.duration,
+            ..default()
+        }
+        .add_lines(lines);
+    }
+}
+
+impl<'a> ShapeHandle<'a, CatmullRom> {
+    pub fn points(self, points: impl IntoIterator<Item = Vec3>) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.points = points.into_iter().collect();
+        }
+        self
+    }
+
+    pub fn closed(self, closed: bool) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.closed = closed;
+        }
+        self
+    }
+
+    /// Splits each span between two points into the given number of lines.
+    pub fn segments(self, segments: u32) -> Self {
+        self.subdivision(Subdivision::Fixed(segments))
+    }
+
+    /// Splits the curve into lines no further than `tolerance` from it.
+    pub fn adaptive(self, tolerance: f32) -> Self {
+        self.subdivision(Subdivision::Adaptive { tolerance })
+    }
+
+    pub fn subdivision(self, subdivision: Subdivision) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.subdivision = subdivision;
+        }
+        self
+    }
+
+    /// Draws the lines between the points the curve goes through.
+    pub fn control_polygon(self, control_polygon: bool) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.controls.polygon = control_polygon;
+        }
+        self
+    }
+
+    /// Draws a small cross on each point the curve goes through.
+    pub fn control_points(self, control_points: bool) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.controls.points = control_points;
+        }
+        self
+    }
+
+    pub fn control_color(self, color: Color) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.controls.color = color;
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::CatmullRom(catmull_rom) = &mut self.shapes.shapes[self.index] {
+            catmull_rom.duration = duration;
+        }
+        self
+    }
+}
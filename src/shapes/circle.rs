@@ -8,6 +8,8 @@ pub struct Circle {
     pub segments: u32,
     pub rotation: Quat,
     pub color: Color,
+    /// Color the circle is filled with, usually translucent. Only the outline is drawn if not set.
+    pub fill: Option<Color>,
     pub duration: f32,
 }
 
@@ -15,6 +17,40 @@ impl Circle {
     pub fn new() -> Self {
         Self::default()
     }
+
+    // A circle around the Y axis of `rotation`, `height` above `position`. Used as a ring of the
+    // shapes standing along their Y axis.
+    pub(crate) fn ring(position: Vec3, rotation: Quat, height: f32) -> Self {
+        Self {
+            position: position + rotation.mul_vec3(Vec3::Y * height),
+            rotation: rotation.mul_quat(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            ..default()
+        }
+    }
+
+    // Point of the circle at the given angle, in radians.
+    pub(crate) fn point(&self, angle: f32) -> Vec3 {
+        self.position
+            + self
+                .rotation
+                .mul_vec3(Vec3::new(angle.cos(), angle.sin(), 0.0) * self.radius)
+    }
+
+    // Adds the lines of the part of the circle between two angles, in radians. The arc gets its
+    // share of the segments of the whole circle.
+    pub(crate) fn add_arc(&self, lines: &mut crate::DebugLines, start_angle: f32, end_angle: f32) {
+        if self.segments == 0 {
+            return;
+        }
+        let fraction = (end_angle - start_angle).abs() / std::f32::consts::TAU;
+        let segments = ((self.segments as f32 * fraction).round() as u32).max(1);
+        let step_size = (end_angle - start_angle) / segments as f32;
+        for i in 1..=segments {
+            let start = self.point(start_angle + step_size * (i - 1) as f32);
+            let end = self.point(start_angle + step_size * i as f32);
+            lines.line_colored(start, end, self.duration, self.color);
+        }
+    }
 }
 
 impl Default for Circle {
@@ -25,6 +61,7 @@ impl Default for Circle {
             segments: 16,
             rotation: Quat::IDENTITY,
             color: Color::WHITE,
+            fill: None,
             duration: 0.0,
         }
     }
@@ -38,20 +75,15 @@ impl From<Circle> for Shape {
 
 impl AddLines for Circle {
     fn add_lines(&self, lines: &mut crate::DebugLines) {
-        let step_size = std::f32::consts::TAU / self.segments as f32;
-        for i in 1..=self.segments {
-            let start_angle = step_size * (i - 1) as f32;
-            let end_angle = step_size * i as f32;
-            let start = self.position
-                + self
-                    .rotation
-                    .mul_vec3(Vec3::new(start_angle.cos(), start_angle.sin(), 0.0) * self.radius);
-            let end = self.position
-                + self
-                    .rotation
-                    .mul_vec3(Vec3::new(end_angle.cos(), end_angle.sin(), 0.0) * self.radius);
+        self.add_arc(lines, 0.0, std::f32::consts::TAU);
 
-            lines.line_colored(start, end, self.duration, self.color);
+        if let Some(fill) = self.fill {
+            let step_size = std::f32::consts::TAU / self.segments as f32;
+            for i in 0..self.segments {
+                let start = self.point(step_size * i as f32);
+                let end = self.point(step_size * (i + 1) as f32);
+                lines.triangle(self.position, start, end, self.duration, fill);
+            }
         }
     }
 }
@@ -92,6 +124,14 @@ impl<'a> ShapeHandle<'a, Circle> {
         self
     }
 
+    /// Fills the circle with the given color, usually translucent.
+    pub fn fill(self, fill: Color) -> Self {
+        if let Shape::Circle(circle) = &mut self.shapes.shapes[self.index] {
+            circle.fill = Some(fill);
+        }
+        self
+    }
+
     pub fn duration(self, duration: f32) -> Self {
         if let Shape::Circle(circle) = &mut self.shapes.shapes[self.index] {
             circle.duration = duration;
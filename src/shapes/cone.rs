@@ -0,0 +1,117 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use super::{AddLines, Circle, Shape, ShapeHandle};
+
+/// A cone standing along its local Y axis, with its apex up and centered on its position.
+pub struct Cone {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub radius: f32,
+    pub height: f32,
+    pub segments: u32,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl Cone {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A ring of the cone, `height` above its center.
+    fn ring(&self, height: f32) -> Circle {
+        Circle {
+            radius: self.radius,
+            segments: self.segments,
+            color: self.color,
+            duration: self.duration,
+            ..Circle::ring(self.position, self.rotation, height)
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            radius: 0.5,
+            height: 1.0,
+            segments: 16,
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Cone> for Shape {
+    fn from(cone: Cone) -> Self {
+        Shape::Cone(cone)
+    }
+}
+
+impl AddLines for Cone {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let base = self.ring(-self.height * 0.5);
+        let apex = self.position + self.rotation.mul_vec3(Vec3::Y * self.height * 0.5);
+        base.add_lines(lines);
+
+        for i in 0..4 {
+            let angle = i as f32 * FRAC_PI_2;
+            lines.line_colored(base.point(angle), apex, self.duration, self.color);
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Cone> {
+    pub fn position(self, position: Vec3) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.position = position;
+        }
+        self
+    }
+
+    pub fn rotation(self, rotation: Quat) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.rotation = rotation;
+        }
+        self
+    }
+
+    pub fn radius(self, radius: f32) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.radius = radius;
+        }
+        self
+    }
+
+    pub fn height(self, height: f32) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.height = height;
+        }
+        self
+    }
+
+    pub fn segments(self, segments: u32) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.segments = segments;
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Cone(cone) = &mut self.shapes.shapes[self.index] {
+            cone.duration = duration;
+        }
+        self
+    }
+}
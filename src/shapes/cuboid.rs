@@ -56,18 +56,18 @@ impl AddLines for Cuboid {
         let v7 = self.position + self.rotation.mul_vec3(v7);
         let v8 = self.position + self.rotation.mul_vec3(v8);
 
-        lines.line_colored(v1, v2, self.duration, self.color);
-        lines.line_colored(v2, v3, self.duration, self.color);
-        lines.line_colored(v3, v4, self.duration, self.color);
-        lines.line_colored(v4, v1, self.duration, self.color);
-        lines.line_colored(v5, v6, self.duration, self.color);
-        lines.line_colored(v6, v7, self.duration, self.color);
-        lines.line_colored(v7, v8, self.duration, self.color);
-        lines.line_colored(v8, v5, self.duration, self.color);
-        lines.line_colored(v1, v5, self.duration, self.color);
-        lines.line_colored(v2, v6, self.duration, self.color);
-        lines.line_colored(v3, v7, self.duration, self.color);
-        lines.line_colored(v4, v8, self.duration, self.color);
+        add_box_edges(lines, [v1, v2, v3, v4, v5, v6, v7, v8], self.duration, self.color);
+    }
+}
+
+// Adds the 12 edges of a box, from the corners of one face followed by the matching corners of the
+// opposite face, each face going around in order.
+pub(crate) fn add_box_edges(lines: &mut crate::DebugLines, corners: [Vec3; 8], duration: f32, color: Color) {
+    for i in 0..4 {
+        let j = (i + 1) % 4;
+        lines.line_colored(corners[i], corners[j], duration, color);
+        lines.line_colored(corners[i + 4], corners[j + 4], duration, color);
+        lines.line_colored(corners[i], corners[i + 4], duration, color);
     }
 }
 
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+// Bounds on how many times an interval of a curve is halved by `Subdivision::Adaptive`, so that
+// S shaped spans are split at least a few times and tiny tolerances stay cheap.
+const MIN_DEPTH: u32 = 2;
+const MAX_DEPTH: u32 = 10;
+
+/// How a curve is split into lines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Subdivision {
+    /// Splits each span of the curve into the same number of lines.
+    Fixed(u32),
+    /// Splits each span of the curve until the lines are no further than `tolerance` from it,
+    /// so tight bends get more lines than straight parts.
+    Adaptive { tolerance: f32 },
+}
+
+impl Default for Subdivision {
+    fn default() -> Self {
+        Subdivision::Fixed(32)
+    }
+}
+
+impl Subdivision {
+    // Points along the span of a curve given by `f` between 0 and 1, including both ends.
+    pub(crate) fn points(&self, f: impl Fn(f32) -> Vec3) -> Vec<Vec3> {
+        match *self {
+            Subdivision::Fixed(segments) => {
+                let segments = segments.max(1);
+                (0..=segments).map(|i| f(i as f32 / segments as f32)).collect()
+            },
+            Subdivision::Adaptive { tolerance } => {
+                let mut points = vec![f(0.0)];
+                // Intervals still to check, the leftmost one on top.
+                let mut intervals = vec![(0.0, points[0], 1.0, f(1.0), 0)];
+                while let Some((t0, p0, t1, p1, depth)) = intervals.pop() {
+                    let t = (t0 + t1) * 0.5;
+                    let p = f(t);
+                    let too_far = p.distance((p0 + p1) * 0.5) > tolerance;
+                    if depth < MIN_DEPTH || (too_far && depth < MAX_DEPTH) {
+                        intervals.push((t, p, t1, p1, depth + 1));
+                        intervals.push((t0, p0, t, p, depth + 1));
+                    } else {
+                        points.push(p1);
+                    }
+                }
+                points
+            },
+        }
+    }
+}
+
+/// How the control points of a curve are shown.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveControls {
+    /// Whether to draw the lines between the control points.
+    pub polygon: bool,
+    /// Whether to draw a small cross on each control point.
+    pub points: bool,
+    pub color: Color,
+    /// Size of the crosses on the control points.
+    pub point_size: f32,
+}
+
+impl Default for CurveControls {
+    fn default() -> Self {
+        Self {
+            polygon: false,
+            points: false,
+            color: Color::GRAY,
+            point_size: 0.1,
+        }
+    }
+}
+
+impl CurveControls {
+    pub(crate) fn add_lines(&self, lines: &mut crate::DebugLines, points: &[Vec3], closed: bool, duration: f32) {
+        if self.polygon {
+            for pair in points.windows(2) {
+                lines.line_colored(pair[0], pair[1], duration, self.color);
+            }
+            if let (true, [first, .., last]) = (closed, points) {
+                lines.line_colored(*last, *first, duration, self.color);
+            }
+        }
+        if self.points {
+            let half = self.point_size * 0.5;
+            for &point in points {
+                for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                    lines.line_colored(point - axis * half, point + axis * half, duration, self.color);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use super::{AddLines, Circle, Shape, ShapeHandle};
+
+/// A cylinder standing along its local Y axis, centered on its position.
+pub struct Cylinder {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub radius: f32,
+    pub height: f32,
+    pub segments: u32,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl Cylinder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A ring of the cylinder, `height` above its center.
+    fn ring(&self, height: f32) -> Circle {
+        Circle {
+            radius: self.radius,
+            segments: self.segments,
+            color: self.color,
+            duration: self.duration,
+            ..Circle::ring(self.position, self.rotation, height)
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            radius: 0.5,
+            height: 1.0,
+            segments: 16,
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Cylinder> for Shape {
+    fn from(cylinder: Cylinder) -> Self {
+        Shape::Cylinder(cylinder)
+    }
+}
+
+impl AddLines for Cylinder {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let bottom = self.ring(-self.height * 0.5);
+        let top = self.ring(self.height * 0.5);
+        bottom.add_lines(lines);
+        top.add_lines(lines);
+
+        for i in 0..4 {
+            let angle = i as f32 * FRAC_PI_2;
+            lines.line_colored(bottom.point(angle), top.point(angle), self.duration, self.color);
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Cylinder> {
+    pub fn position(self, position: Vec3) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.position = position;
+        }
+        self
+    }
+
+    pub fn rotation(self, rotation: Quat) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.rotation = rotation;
+        }
+        self
+    }
+
+    pub fn radius(self, radius: f32) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.radius = radius;
+        }
+        self
+    }
+
+    pub fn height(self, height: f32) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.height = height;
+        }
+        self
+    }
+
+    pub fn segments(self, segments: u32) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.segments = segments;
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Cylinder(cylinder) = &mut self.shapes.shapes[self.index] {
+            cylinder.duration = duration;
+        }
+        self
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::{prelude::*, render::camera::CameraProjection};
+
+use super::{cuboid::add_box_edges, AddLines, Shape, ShapeHandle};
+
+/// The volume seen by a camera with the given projection, placed at `transform`.
+///
+/// Perspective projections give a truncated pyramid and orthographic ones a box.
+pub struct Frustum {
+    pub projection: Projection,
+    pub transform: GlobalTransform,
+    /// Distance of the far plane, overriding the one of the projection. Useful to see the shape
+    /// of a camera whose far plane is very distant.
+    pub far: Option<f32>,
+    pub color: Color,
+    pub duration: f32,
+}
+
+impl Frustum {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Frustum {
+    fn default() -> Self {
+        Self {
+            projection: Projection::default(),
+            transform: GlobalTransform::IDENTITY,
+            far: None,
+            color: Color::WHITE,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Frustum> for Shape {
+    fn from(frustum: Frustum) -> Self {
+        Shape::Frustum(frustum)
+    }
+}
+
+impl AddLines for Frustum {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let near = match &self.projection {
+            Projection::Perspective(projection) => projection.near,
+            Projection::Orthographic(projection) => projection.near,
+        };
+        let far = self.far.unwrap_or_else(|| self.projection.far());
+
+        // Cameras look down their -Z axis.
+        let corners = self
+            .projection
+            .get_frustum_corners(-near, -far)
+            .map(|corner| self.transform.transform_point(corner.into()));
+        add_box_edges(lines, corners, self.duration, self.color);
+    }
+}
+
+impl<'a> ShapeHandle<'a, Frustum> {
+    pub fn projection(self, projection: impl Into<Projection>) -> Self {
+        if let Shape::Frustum(frustum) = &mut self.shapes.shapes[self.index] {
+            frustum.projection = projection.into();
+        }
+        self
+    }
+
+    pub fn transform(self, transform: &Transform) -> Self {
+        self.global_transform(&GlobalTransform::from(*transform))
+    }
+
+    pub fn global_transform(self, transform: &GlobalTransform) -> Self {
+        if let Shape::Frustum(frustum) = &mut self.shapes.shapes[self.index] {
+            frustum.transform = *transform;
+        }
+        self
+    }
+
+    /// Sets the distance of the far plane, instead of the one of the projection.
+    pub fn far(self, far: f32) -> Self {
+        if let Shape::Frustum(frustum) = &mut self.shapes.shapes[self.index] {
+            frustum.far = Some(far);
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Frustum(frustum) = &mut self.shapes.shapes[self.index] {
+            frustum.color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Frustum(frustum) = &mut self.shapes.shapes[self.index] {
+            frustum.duration = duration;
+        }
+        self
+    }
+}
@@ -0,0 +1,293 @@
+use bevy::prelude::*;
+
+use super::{AddLines, Shape, ShapeHandle};
+use crate::{DebugShapes, LineDimension};
+
+// Number of gradient segments each line of a following grid is split into to fade it out.
+const FADE_SEGMENTS: u32 = 8;
+
+/// Plane a [`Grid`] lies in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridPlane {
+    /// The ground plane of a Y up world.
+    #[default]
+    XZ,
+    XY,
+    YZ,
+}
+
+impl GridPlane {
+    // The two axes the lines of the grid run along.
+    fn axes(self) -> (Vec3, Vec3) {
+        match self {
+            GridPlane::XZ => (Vec3::X, Vec3::Z),
+            GridPlane::XY => (Vec3::X, Vec3::Y),
+            GridPlane::YZ => (Vec3::Y, Vec3::Z),
+        }
+    }
+
+    // Coordinates of the projection of `point` on the plane going through `origin`, along each axis.
+    fn project(self, origin: Vec3, point: Vec3) -> Vec2 {
+        let (u, v) = self.axes();
+        Vec2::new((point - origin).dot(u), (point - origin).dot(v))
+    }
+}
+
+/// Lines spaced by `cell_size` in a plane going through `position`, with every `major_every`th
+/// line drawn in `major_color`.
+///
+/// The grid covers `extent` around `position` in each direction. When following a point, see
+/// [`Grid::follow`], it is centered on that point instead and fades out towards its edge, which
+/// makes it look infinite.
+pub struct Grid {
+    pub plane: GridPlane,
+    pub position: Vec3,
+    pub cell_size: f32,
+    /// Interval between major lines, in cells. There are no major lines if 0.
+    pub major_every: u32,
+    pub extent: f32,
+    /// Point the grid follows, usually the position of the camera.
+    pub follow: Option<Vec3>,
+    pub color: Color,
+    pub major_color: Color,
+    pub duration: f32,
+}
+
+impl Grid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self {
+            plane: GridPlane::default(),
+            position: Vec3::ZERO,
+            cell_size: 1.0,
+            major_every: 10,
+            extent: 10.0,
+            follow: None,
+            color: Color::rgb(0.25, 0.25, 0.25),
+            major_color: Color::GRAY,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Grid> for Shape {
+    fn from(grid: Grid) -> Self {
+        Shape::Grid(grid)
+    }
+}
+
+impl AddLines for Grid {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let (u, v) = self.plane.axes();
+        let point = |at: Vec2| self.position + u * at.x + v * at.y;
+        // Center of the grid along each axis of the plane, and the radius of the part of the plane
+        // within `extent` of the followed point, which gets smaller the further the point is from it.
+        let (center, extent) = match self.follow {
+            Some(follow) => {
+                let center = self.plane.project(self.position, follow);
+                let height = point(center).distance(follow);
+                (center, (self.extent.powi(2) - height.powi(2)).max(0.0).sqrt())
+            },
+            None => (Vec2::ZERO, self.extent),
+        };
+        if self.cell_size <= 0.0 || extent <= 0.0 {
+            return;
+        }
+        // Fades the color with the distance to the followed point.
+        let fade = |color: Color, at: Vec2| {
+            let distance = self.follow.map_or(0.0, |follow| point(at).distance(follow));
+            let fade = (1.0 - distance / self.extent).clamp(0.0, 1.0);
+            color.with_a(color.a() * fade)
+        };
+
+        // Faded lines are translucent, so they don't hide what is behind them.
+        let mut lines = lines.translucent(self.follow.is_some());
+
+        // Lines along v at each u, then along u at each v.
+        for axis in [Vec2::X, Vec2::Y] {
+            let other = axis.yx();
+            let (across, along) = (center.dot(axis), center.dot(other));
+            let first = ((across - extent) / self.cell_size).ceil() as i64;
+            let last = ((across + extent) / self.cell_size).floor() as i64;
+            for i in first..=last {
+                let major = self.major_every > 0 && i.rem_euclid(self.major_every as i64) == 0;
+                let color = if major { self.major_color } else { self.color };
+                let offset = axis * i as f32 * self.cell_size;
+
+                if self.follow.is_none() {
+                    let start = offset + other * (along - extent);
+                    let end = offset + other * (along + extent);
+                    lines.line_colored(point(start), point(end), self.duration, color);
+                    continue;
+                }
+                // Only the part of the line within `extent` of the followed point is visible, split
+                // into a few segments so the fade roughly follows the distance along the line.
+                let half_length = (extent.powi(2) - (offset.dot(axis) - across).powi(2)).max(0.0).sqrt();
+                let start = offset + other * (along - half_length);
+                let end = offset + other * (along + half_length);
+                for step in 0..FADE_SEGMENTS {
+                    let from = start.lerp(end, step as f32 / FADE_SEGMENTS as f32);
+                    let to = start.lerp(end, (step + 1) as f32 / FADE_SEGMENTS as f32);
+                    let (from_color, to_color) = (fade(color, from), fade(color, to));
+                    lines.line_gradient(point(from), point(to), self.duration, from_color, to_color);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Grid> {
+    pub fn plane(self, plane: GridPlane) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.plane = plane;
+        }
+        self
+    }
+
+    pub fn position(self, position: Vec3) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.position = position;
+        }
+        self
+    }
+
+    pub fn cell_size(self, cell_size: f32) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.cell_size = cell_size;
+        }
+        self
+    }
+
+    /// Sets the interval between major lines, in cells.
+    pub fn major_every(self, major_every: u32) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.major_every = major_every;
+        }
+        self
+    }
+
+    pub fn extent(self, extent: f32) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.extent = extent;
+        }
+        self
+    }
+
+    /// Centers the grid on the given point, fading it out towards its edge.
+    pub fn follow(self, point: Vec3) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.follow = Some(point);
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.color = color;
+        }
+        self
+    }
+
+    pub fn major_color(self, color: Color) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.major_color = color;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Grid(grid) = &mut self.shapes.shapes[self.index] {
+            grid.duration = duration;
+        }
+        self
+    }
+}
+
+/// Draws an infinite looking [`Grid`] following the camera it is added to.
+///
+/// The grid is only drawn for that camera, and not at all while the camera is inactive. It lies in
+/// the XZ plane by default, which a 2d camera only sees edge-on: use [`GridPlane::XY`] for those,
+/// with sizes in pixels. The distance of a 2d camera to the plane is ignored.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn spawn_editor_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera3dBundle::default(),
+///         ShowGrid {
+///             extent: 100.0,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShowGrid {
+    pub plane: GridPlane,
+    /// A point of the plane the grid lies in.
+    pub position: Vec3,
+    pub cell_size: f32,
+    pub major_every: u32,
+    /// Distance from the camera at which the grid has faded out.
+    pub extent: f32,
+    pub color: Color,
+    pub major_color: Color,
+    /// Whether the grid is hidden behind other geometry, `true` by default so it doesn't draw
+    /// over the scene.
+    pub depth_test: bool,
+}
+
+impl Default for ShowGrid {
+    fn default() -> Self {
+        let grid = Grid::default();
+        Self {
+            plane: grid.plane,
+            position: grid.position,
+            cell_size: grid.cell_size,
+            major_every: grid.major_every,
+            extent: 50.0,
+            color: grid.color,
+            major_color: grid.major_color,
+            depth_test: true,
+        }
+    }
+}
+
+// Draws the grid of the active cameras with `ShowGrid`.
+pub(crate) fn draw_grids(
+    mut shapes: ResMut<DebugShapes>,
+    cameras: Query<(Entity, &ShowGrid, &Camera, &GlobalTransform, Has<Camera2d>)>,
+) {
+    for (entity, show_grid, camera, transform, is_2d) in cameras.iter() {
+        if !camera.is_active {
+            continue;
+        }
+
+        let dimension = if is_2d { LineDimension::TwoD } else { LineDimension::ThreeD };
+        let mut follow = transform.translation();
+        if is_2d {
+            let (u, v) = show_grid.plane.axes();
+            let at = show_grid.plane.project(show_grid.position, follow);
+            follow = show_grid.position + u * at.x + v * at.y;
+        }
+        shapes
+            .grid()
+            .plane(show_grid.plane)
+            .position(show_grid.position)
+            .cell_size(show_grid.cell_size)
+            .major_every(show_grid.major_every)
+            .extent(show_grid.extent)
+            .follow(follow)
+            .color(show_grid.color)
+            .major_color(show_grid.major_color)
+            .depth_test(show_grid.depth_test)
+            .camera(entity)
+            .dimension(dimension);
+    }
+}
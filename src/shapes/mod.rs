@@ -1,16 +1,50 @@
 use std::marker::PhantomData;
 
-use bevy::prelude::*;
+use bevy::{prelude::*, render::view::RenderLayers};
 
-use crate::DebugLines;
+use crate::{DebugLines, LineDash, LineDimension, LineSpace, LineStyle, LineWidth};
 
-pub use self::{circle::Circle, cuboid::Cuboid, line::Line, rect::Rect, sphere::Sphere};
+pub use self::{
+    arrow::Arrow,
+    axes::{Axes, ShowAxes},
+    bezier::Bezier,
+    capsule::Capsule,
+    catmull_rom::CatmullRom,
+    circle::Circle,
+    cone::Cone,
+    cuboid::Cuboid,
+    curve::{CurveControls, Subdivision},
+    cylinder::Cylinder,
+    frustum::Frustum,
+    grid::{Grid, GridPlane, ShowGrid},
+    line::Line,
+    polygon::Polygon,
+    polyline::Polyline,
+    rect::Rect,
+    sphere::Sphere,
+    triangle::Triangle,
+};
 
+pub(crate) use self::{axes::draw_axes, grid::draw_grids};
+
+mod arrow;
+mod axes;
+mod bezier;
+mod capsule;
+mod catmull_rom;
 mod circle;
+mod cone;
 mod cuboid;
+mod curve;
+mod cylinder;
+mod frustum;
+mod grid;
 mod line;
+mod polygon;
+mod polyline;
 mod rect;
 mod sphere;
+mod triangle;
 
 /// Bevy resource providing facilities to draw shapes.
 ///
@@ -32,9 +66,35 @@ mod sphere;
 ///         .color(Color::RED);
 /// }
 /// ```
+///
+/// Properties shared by all shapes, such as the line width, are also set on the [`ShapeHandle`].
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn some_system(mut shapes: ResMut<DebugShapes>) {
+///     shapes.sphere().radius(2.0).width(3.0);
+/// }
+/// ```
+///
+/// Rects, circles, triangles and polygons can also be filled, usually with a translucent color.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn draw_trigger(mut shapes: ResMut<DebugShapes>) {
+///     shapes
+///         .circle()
+///         .radius(3.0)
+///         .rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+///         .color(Color::GREEN)
+///         .fill(Color::rgba(0.0, 1.0, 0.0, 0.2));
+/// }
+/// ```
 #[derive(Resource, Default)]
 pub struct DebugShapes {
     pub shapes: Vec<Shape>,
+    styles: Vec<LineStyle>,
 }
 
 impl DebugShapes {
@@ -45,9 +105,109 @@ impl DebugShapes {
     {
         let index = self.shapes.len();
         self.shapes.push(shape.into());
+        self.styles.resize(self.shapes.len(), LineStyle::default());
         ShapeHandle::new(self, index)
     }
 
+    /// Adds an [`Arrow`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Arrow`] for more shape properties.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(Vec3);
+    ///
+    /// fn draw_velocities(
+    ///     mut shapes: ResMut<DebugShapes>,
+    ///     bodies: Query<(&Transform, &Velocity)>,
+    ///     camera: Query<&Transform, With<Camera>>,
+    /// ) {
+    ///     let camera = camera.single().translation;
+    ///     for (transform, velocity) in bodies.iter() {
+    ///         shapes
+    ///             .arrow()
+    ///             .vector(transform.translation, velocity.0)
+    ///             .fins(2)
+    ///             .facing(camera)
+    ///             .color(Color::YELLOW);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn arrow(&mut self) -> ShapeHandle<'_, Arrow> {
+        self.add(Arrow::new())
+    }
+
+    /// Adds an [`Axes`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Axes`] for more shape properties.
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn axes(&mut self) -> ShapeHandle<'_, Axes> {
+        self.add(Axes::new())
+    }
+
+    /// Adds a [`Bezier`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Bezier`] for more shape properties.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn draw_trajectory(mut shapes: ResMut<DebugShapes>) {
+    ///     shapes
+    ///         .bezier()
+    ///         .quadratic(Vec3::ZERO, Vec3::new(2.0, 4.0, 0.0), Vec3::new(4.0, 0.0, 0.0))
+    ///         .adaptive(0.01)
+    ///         .control_polygon(true)
+    ///         .color(Color::RED);
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn bezier(&mut self) -> ShapeHandle<'_, Bezier> {
+        self.add(Bezier::new())
+    }
+
+    /// Adds a [`Capsule`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Capsule`] for more shape properties.
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn capsule(&mut self) -> ShapeHandle<'_, Capsule> {
+        self.add(Capsule::new())
+    }
+
+    /// Adds a [`CatmullRom`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`CatmullRom`] for more shape properties.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct CameraRail(Vec<Vec3>);
+    ///
+    /// fn draw_rail(mut shapes: ResMut<DebugShapes>, rail: Res<CameraRail>) {
+    ///     shapes
+    ///         .catmull_rom()
+    ///         .points(rail.0.iter().copied())
+    ///         .closed(true)
+    ///         .control_points(true);
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn catmull_rom(&mut self) -> ShapeHandle<'_, CatmullRom> {
+        self.add(CatmullRom::new())
+    }
+
     /// Adds a [`Circle`] shape.
     ///
     /// See [`ShapeHandle`] impl on [`Circle`] for more shape properties.
@@ -57,6 +217,15 @@ impl DebugShapes {
         self.add(Circle::new())
     }
 
+    /// Adds a [`Cone`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Cone`] for more shape properties.
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn cone(&mut self) -> ShapeHandle<'_, Cone> {
+        self.add(Cone::new())
+    }
+
     /// Adds a [`Cuboid`] shape.
     ///
     /// See [`ShapeHandle`] impl on [`Cuboid`] for more shape properties.
@@ -66,6 +235,56 @@ impl DebugShapes {
         self.add(Cuboid::new())
     }
 
+    /// Adds a [`Cylinder`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Cylinder`] for more shape properties.
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn cylinder(&mut self) -> ShapeHandle<'_, Cylinder> {
+        self.add(Cylinder::new())
+    }
+
+    /// Adds a [`Frustum`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Frustum`] for more shape properties.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// #[derive(Component)]
+    /// struct SecurityCamera;
+    ///
+    /// fn draw_camera_frustums(
+    ///     mut shapes: ResMut<DebugShapes>,
+    ///     cameras: Query<(&Projection, &GlobalTransform), With<SecurityCamera>>,
+    /// ) {
+    ///     for (projection, transform) in cameras.iter() {
+    ///         shapes
+    ///             .frustum()
+    ///             .projection(projection.clone())
+    ///             .global_transform(transform)
+    ///             .far(20.0)
+    ///             .color(Color::YELLOW);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn frustum(&mut self) -> ShapeHandle<'_, Frustum> {
+        self.add(Frustum::new())
+    }
+
+    /// Adds a [`Grid`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Grid`] for more shape properties, and [`ShowGrid`] for a
+    /// grid following a camera.
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn grid(&mut self) -> ShapeHandle<'_, Grid> {
+        self.add(Grid::new())
+    }
+
     /// Adds a [`Line`] shape.
     ///
     /// See [`ShapeHandle`] impl on [`Line`] for more shape properties.
@@ -75,6 +294,55 @@ impl DebugShapes {
         self.add(Line::new())
     }
 
+    /// Adds a [`Polygon`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Polygon`] for more shape properties.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// fn draw_zone(mut shapes: ResMut<DebugShapes>) {
+    ///     shapes
+    ///         .polygon()
+    ///         .position(Vec3::new(0.0, 0.1, 0.0))
+    ///         .rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+    ///         .regular(6, 2.0)
+    ///         .dash(LineDash::world(0.2, 0.1))
+    ///         .continuous_dash(true)
+    ///         .color(Color::ORANGE);
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn polygon(&mut self) -> ShapeHandle<'_, Polygon> {
+        self.add(Polygon::new())
+    }
+
+    /// Adds a [`Polyline`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Polyline`] for more shape properties.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_prototype_debug_lines::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Path(Vec<Vec3>);
+    ///
+    /// fn draw_paths(mut shapes: ResMut<DebugShapes>, agents: Query<&Path>) {
+    ///     for path in agents.iter() {
+    ///         let fade = (0..path.0.len()).map(|i| Color::GREEN.with_a(1.0 / (i + 1) as f32));
+    ///         shapes.polyline().points(path.0.iter().copied()).colors(fade);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn polyline(&mut self) -> ShapeHandle<'_, Polyline> {
+        self.add(Polyline::new())
+    }
+
     /// Adds a [`Sphere`] shape.
     ///
     /// See [`ShapeHandle`] impl on [`Sphere`] for more shape properties.
@@ -92,6 +360,28 @@ impl DebugShapes {
     pub fn rect(&mut self) -> ShapeHandle<'_, Rect> {
         self.add(Rect::new())
     }
+
+    /// Adds a [`Triangle`] shape.
+    ///
+    /// See [`ShapeHandle`] impl on [`Triangle`] for more shape properties.
+    ///
+    /// Short for [`DebugShapes::add`].
+    pub fn triangle(&mut self) -> ShapeHandle<'_, Triangle> {
+        self.add(Triangle::new())
+    }
+}
+
+impl DebugShapes {
+    // Iterates over the shapes with the style they should be drawn with.
+    pub(crate) fn styled(&self) -> impl Iterator<Item = (&Shape, LineStyle)> {
+        let styles = self.styles.iter().copied().chain(std::iter::repeat(LineStyle::default()));
+        self.shapes.iter().zip(styles)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.shapes.clear();
+        self.styles.clear();
+    }
 }
 
 /// Implemented on shapes to add lines to [`DebugLines`].
@@ -102,21 +392,45 @@ pub(crate) trait AddLines {
 
 /// Wrapper around all shape types to allow matching to specific shapes.
 pub enum Shape {
+    Arrow(Arrow),
+    Axes(Axes),
+    Bezier(Bezier),
+    Capsule(Capsule),
+    CatmullRom(CatmullRom),
     Circle(Circle),
+    Cone(Cone),
     Cuboid(Cuboid),
+    Cylinder(Cylinder),
+    Frustum(Frustum),
+    Grid(Grid),
     Line(Line),
+    Polygon(Polygon),
+    Polyline(Polyline),
     Rect(Rect),
     Sphere(Sphere),
+    Triangle(Triangle),
 }
 
 impl AddLines for Shape {
     fn add_lines(&self, lines: &mut DebugLines) {
         match self {
+            Shape::Arrow(s) => s.add_lines(lines),
+            Shape::Axes(s) => s.add_lines(lines),
+            Shape::Bezier(s) => s.add_lines(lines),
+            Shape::Capsule(s) => s.add_lines(lines),
+            Shape::CatmullRom(s) => s.add_lines(lines),
             Shape::Circle(s) => s.add_lines(lines),
+            Shape::Cone(s) => s.add_lines(lines),
             Shape::Cuboid(s) => s.add_lines(lines),
+            Shape::Cylinder(s) => s.add_lines(lines),
+            Shape::Frustum(s) => s.add_lines(lines),
+            Shape::Grid(s) => s.add_lines(lines),
             Shape::Line(s) => s.add_lines(lines),
+            Shape::Polygon(s) => s.add_lines(lines),
+            Shape::Polyline(s) => s.add_lines(lines),
             Shape::Rect(s) => s.add_lines(lines),
             Shape::Sphere(s) => s.add_lines(lines),
+            Shape::Triangle(s) => s.add_lines(lines),
         }
     }
 }
@@ -136,4 +450,54 @@ impl<'a, S> ShapeHandle<'a, S> {
             _ty: PhantomData,
         }
     }
+
+    /// Sets the width of the shape lines, either a [`LineWidth`] or a number of pixels.
+    pub fn width(self, width: impl Into<LineWidth>) -> Self {
+        self.shapes.styles[self.index].width = width.into();
+        self
+    }
+
+    /// Adds the shape lines to a named group, see [`DebugLines::group`].
+    pub fn group(self, name: &'static str) -> Self {
+        self.shapes.styles[self.index].group = Some(name);
+        self
+    }
+
+    /// Sets the render layers the shape is drawn on, see [`DebugLines::render_layers`].
+    pub fn render_layers(self, render_layers: RenderLayers) -> Self {
+        self.shapes.styles[self.index].render_layers = Some(render_layers);
+        self
+    }
+
+    /// Draws the shape only for the given camera, see [`DebugLines::for_camera`].
+    pub fn camera(self, camera: Entity) -> Self {
+        self.shapes.styles[self.index].camera = Some(camera);
+        self
+    }
+
+    /// Sets the coordinate space the shape is given in, see [`DebugLines::space`].
+    pub fn space(self, space: LineSpace) -> Self {
+        self.shapes.styles[self.index].space = space;
+        self
+    }
+
+    /// Sets whether the shape is hidden behind other geometry, overriding the plugin setting.
+    ///
+    /// See [`DebugLines::depth_test`].
+    pub fn depth_test(self, depth_test: bool) -> Self {
+        self.shapes.styles[self.index].depth_test = Some(depth_test);
+        self
+    }
+
+    /// Draws the lines of the shape with a dash pattern, see [`DebugLines::dash`].
+    pub fn dash(self, dash: LineDash) -> Self {
+        self.shapes.styles[self.index].dash = Some(dash);
+        self
+    }
+
+    /// Sets the kind of camera the shape is drawn for.
+    pub fn dimension(self, dimension: LineDimension) -> Self {
+        self.shapes.styles[self.index].dimension = dimension;
+        self
+    }
 }
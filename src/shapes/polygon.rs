@@ -0,0 +1,286 @@
+use bevy::prelude::*;
+
+use super::{AddLines, Polyline, Shape, ShapeHandle};
+
+/// A closed outline going through a list of points in its local XY plane.
+pub struct Polygon {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub points: Vec<Vec2>,
+    /// Color of each point, see [`Polyline::colors`].
+    pub colors: Vec<Color>,
+    pub color: Color,
+    /// Color the polygon is filled with, usually translucent. Only the outline is drawn if not
+    /// set. The polygon can be concave, but its outline shouldn't cross itself.
+    pub fill: Option<Color>,
+    /// See [`Polyline::continuous_dash`].
+    pub continuous_dash: bool,
+    pub duration: f32,
+}
+
+impl Polygon {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Polygon {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            points: vec![],
+            colors: vec![],
+            color: Color::WHITE,
+            fill: None,
+            continuous_dash: false,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Polygon> for Shape {
+    fn from(polygon: Polygon) -> Self {
+        Shape::Polygon(polygon)
+    }
+}
+
+impl AddLines for Polygon {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let points: Vec<_> = self
+            .points
+            .iter()
+            .map(|point| self.position + self.rotation.mul_vec3(point.extend(0.0)))
+            .collect();
+
+        if let Some(fill) = self.fill {
+            for [a, b, c] in triangulate(&self.points) {
+                lines.triangle(points[a], points[b], points[c], self.duration, fill);
+            }
+        }
+
+        Polyline {
+            points,
+            colors: self.colors.clone(),
+            color: self.color,
+            closed: true,
+            continuous_dash: self.continuous_dash,
+            duration: self.duration,
+        }
+        .add_lines(lines);
+    }
+}
+
+// Splits a simple polygon into triangles by clipping its ears, returning the indices of their
+// corners.
+fn triangulate(points: &[Vec2]) -> Vec<[usize; 3]> {
+    let mut triangles = vec![];
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    // Go around counter-clockwise, so that the corners of ears turn left.
+    let area: f32 = (0..points.len())
+        .map(|i| points[i].perp_dot(points[(i + 1) % points.len()]))
+        .sum();
+    if area < 0.0 {
+        remaining.reverse();
+    }
+
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let ear = (0..count).find(|&i| {
+            let [a, b, c] = [remaining[(i + count - 1) % count], remaining[i], remaining[(i + 1) % count]];
+            let (pa, pb, pc) = (points[a], points[b], points[c]);
+            let convex = (pb - pa).perp_dot(pc - pb) > 0.0;
+            convex
+                && remaining.iter().all(|&other| {
+                    let p = points[other];
+                    [a, b, c].contains(&other)
+                        || (pb - pa).perp_dot(p - pa) < 0.0
+                        || (pc - pb).perp_dot(p - pb) < 0.0
+                        || (pa - pc).perp_dot(p - pc) < 0.0
+                })
+        });
+        // Degenerate outlines have no ear, fill what is left as a fan instead of giving up.
+        let Some(i) = ear else {
+            break;
+        };
+        triangles.push([remaining[(i + count - 1) % count], remaining[i], remaining[(i + 1) % count]]);
+        remaining.remove(i);
+    }
+    for i in 2..remaining.len() {
+        triangles.push([remaining[0], remaining[i - 1], remaining[i]]);
+    }
+    triangles
+}
+
+impl<'a> ShapeHandle<'a, Polygon> {
+    pub fn position(self, position: Vec3) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.position = position;
+        }
+        self
+    }
+
+    pub fn rotation(self, rotation: Quat) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.rotation = rotation;
+        }
+        self
+    }
+
+    pub fn angle(self, angle: f32) -> Self {
+        self.rotation(Quat::from_rotation_z(angle))
+    }
+
+    pub fn points(self, points: impl IntoIterator<Item = Vec2>) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.points = points.into_iter().collect();
+        }
+        self
+    }
+
+    /// Sets the points to the corners of a regular polygon, the first one pointing up.
+    pub fn regular(self, sides: u32, radius: f32) -> Self {
+        let step = std::f32::consts::TAU / sides as f32;
+        self.points((0..sides).map(|i| {
+            let angle = std::f32::consts::FRAC_PI_2 + step * i as f32;
+            Vec2::new(angle.cos(), angle.sin()) * radius
+        }))
+    }
+
+    /// Sets the color of each point, see [`Polyline::colors`].
+    pub fn colors(self, colors: impl IntoIterator<Item = Color>) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.colors = colors.into_iter().collect();
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.color = color;
+        }
+        self
+    }
+
+    /// Fills the polygon with the given color, usually translucent.
+    pub fn fill(self, fill: Color) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.fill = Some(fill);
+        }
+        self
+    }
+
+    /// Carries the dash pattern of the outline on around the corners, see
+    /// [`Polyline::continuous_dash`].
+    pub fn continuous_dash(self, continuous_dash: bool) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.continuous_dash = continuous_dash;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Polygon(polygon) = &mut self.shapes.shapes[self.index] {
+            polygon.duration = duration;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(points: &[Vec2]) -> f32 {
+        (0..points.len())
+            .map(|i| points[i].perp_dot(points[(i + 1) % points.len()]))
+            .sum::<f32>()
+            .abs()
+            / 2.0
+    }
+
+    // Even-odd test, the point shouldn't be on an edge.
+    fn contains(polygon: &[Vec2], point: Vec2) -> bool {
+        let mut inside = false;
+        for i in 0..polygon.len() {
+            let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+            if (a.y > point.y) != (b.y > point.y) && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    // Checks that the triangles cover the polygon exactly, without going outside of it.
+    fn assert_covers(points: &[Vec2]) {
+        let triangles = triangulate(points);
+        assert_eq!(triangles.len(), points.len() - 2);
+        let mut covered = 0.0;
+        for [a, b, c] in triangles {
+            let triangle = [points[a], points[b], points[c]];
+            covered += area(&triangle);
+            let center = (triangle[0] + triangle[1] + triangle[2]) / 3.0;
+            assert!(contains(points, center), "{triangle:?} is outside of the polygon");
+        }
+        assert!((covered - area(points)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn convex() {
+        let hexagon: Vec<_> = (0..6)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / 6.0;
+                Vec2::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert_covers(&hexagon);
+    }
+
+    #[test]
+    fn concave() {
+        let l_shape = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_covers(&l_shape);
+    }
+
+    #[test]
+    fn clockwise() {
+        let l_shape = [
+            Vec2::new(0.0, 2.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 0.0),
+        ];
+        assert_covers(&l_shape);
+    }
+
+    #[test]
+    fn collinear_corner() {
+        let square = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_covers(&square);
+    }
+
+    #[test]
+    fn degenerate() {
+        let line = [Vec2::ZERO, Vec2::X, Vec2::X * 2.0, Vec2::X * 3.0];
+        assert_eq!(triangulate(&line).len(), 2);
+        let repeated = [Vec2::ONE; 5];
+        assert_eq!(triangulate(&repeated).len(), 3);
+        assert!(triangulate(&[Vec2::ZERO, Vec2::X]).is_empty());
+        assert!(triangulate(&[]).is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use super::{AddLines, Shape, ShapeHandle};
+
+/// Connected lines going through a list of points.
+pub struct Polyline {
+    pub points: Vec<Vec3>,
+    /// Color of each point, the line between two points is a gradient. Points without a color
+    /// use `color`.
+    pub colors: Vec<Color>,
+    pub color: Color,
+    /// Whether to connect the last point to the first one.
+    pub closed: bool,
+    /// Whether the dash pattern set with [`ShapeHandle::dash`] carries on around the corners
+    /// instead of starting over at each point. Only patterns in world units can carry on.
+    pub continuous_dash: bool,
+    pub duration: f32,
+}
+
+impl Polyline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn color_at(&self, index: usize) -> Color {
+        self.colors.get(index).copied().unwrap_or(self.color)
+    }
+}
+
+impl Default for Polyline {
+    fn default() -> Self {
+        Self {
+            points: vec![],
+            colors: vec![],
+            color: Color::WHITE,
+            closed: false,
+            continuous_dash: false,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Polyline> for Shape {
+    fn from(polyline: Polyline) -> Self {
+        Shape::Polyline(polyline)
+    }
+}
+
+impl AddLines for Polyline {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let count = self.points.len();
+        let segments = match count {
+            0 | 1 => 0,
+            2 => 1,
+            _ if self.closed => count,
+            _ => count - 1,
+        };
+
+        // Length of the polyline before the current segment, which its dash pattern starts into.
+        let mut travelled = 0.0;
+        for i in 0..segments {
+            let j = (i + 1) % count;
+            let (start, end) = (self.points[i], self.points[j]);
+            let (start_color, end_color) = (self.color_at(i), self.color_at(j));
+
+            if self.continuous_dash {
+                lines
+                    .scoped(|style| style.dash_offset = travelled)
+                    .line_gradient(start, end, self.duration, start_color, end_color);
+                travelled += start.distance(end);
+            } else {
+                lines.line_gradient(start, end, self.duration, start_color, end_color);
+            }
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Polyline> {
+    pub fn points(self, points: impl IntoIterator<Item = Vec3>) -> Self {
+        if let Shape::Polyline(polyline) = &mut self.shapes.shapes[self.index] {
+            polyline.points = points.into_iter().collect();
+        }
+        self
+    }
+
+    /// Sets the points of the polyline in the XY plane.
+    pub fn points_2d(self, points: impl IntoIterator<Item = Vec2>) -> Self {
+        self.points(points.into_iter().map(|point| point.extend(0.0)))
+    }
+
+    /// Sets the color of each point, see [`Polyline::colors`].
+    pub fn colors(self, colors: impl IntoIterator<Item = Color>) -> Self {
+        if let Shape::Polyline(polyline) = &mut self.shapes.shapes[self.index] {
+            polyline.colors = colors.into_iter().collect();
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Polyline(polyline) = &mut self.shapes.shapes[self.index] {
+            polyline.color = color;
+        }
+        self
+    }
+
+    pub fn closed(self, closed: bool) -> Self {
+        if let Shape::Polyline(polyline) = &mut self.shapes.shapes[self.index] {
+            polyline.closed = closed;
+        }
+        self
+    }
+
+    /// Carries the dash pattern on around the corners, see [`Polyline::continuous_dash`].
+    pub fn continuous_dash(self, continuous_dash: bool) -> Self {
+        if let Shape::Polyline(polyline) = &mut self.shapes.shapes[self.index] {
+            polyline.continuous_dash = continuous_dash;
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Polyline(polyline) = &mut self.shapes.shapes[self.index] {
+            polyline.duration = duration;
+        }
+        self
+    }
+}
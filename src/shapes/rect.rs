@@ -7,6 +7,8 @@ pub struct Rect {
     pub extent: Vec2,
     pub rotation: Quat,
     pub color: Color,
+    /// Color the rect is filled with, usually translucent. Only the outline is drawn if not set.
+    pub fill: Option<Color>,
     pub duration: f32,
 }
 
@@ -23,6 +25,7 @@ impl Default for Rect {
             extent: Vec2::ONE,
             rotation: Quat::IDENTITY,
             color: Color::WHITE,
+            fill: None,
             duration: 0.0,
         }
     }
@@ -52,6 +55,11 @@ impl AddLines for Rect {
         lines.line_colored(v2, v3, self.duration, self.color);
         lines.line_colored(v3, v4, self.duration, self.color);
         lines.line_colored(v4, v1, self.duration, self.color);
+
+        if let Some(fill) = self.fill {
+            lines.triangle(v1, v2, v3, self.duration, fill);
+            lines.triangle(v3, v4, v1, self.duration, fill);
+        }
     }
 }
 
@@ -96,6 +104,14 @@ impl<'a> ShapeHandle<'a, Rect> {
         self
     }
 
+    /// Fills the rect with the given color, usually translucent.
+    pub fn fill(self, fill: Color) -> Self {
+        if let Shape::Rect(rect) = &mut self.shapes.shapes[self.index] {
+            rect.fill = Some(fill);
+        }
+        self
+    }
+
     pub fn duration(self, duration: f32) -> Self {
         if let Shape::Rect(rect) = &mut self.shapes.shapes[self.index] {
             rect.duration = duration;
@@ -45,6 +45,7 @@ impl AddLines for Sphere {
             segments: self.segments,
             rotation: self.rotation,
             color: self.color,
+            fill: None,
             duration: self.duration,
         }
         .add_lines(lines);
@@ -54,6 +55,7 @@ impl AddLines for Sphere {
             segments: self.segments,
             rotation: self.rotation.mul_quat(Quat::from_rotation_x(FRAC_PI_2)),
             color: self.color,
+            fill: None,
             duration: self.duration,
         }
         .add_lines(lines);
@@ -63,6 +65,7 @@ impl AddLines for Sphere {
             segments: self.segments,
             rotation: self.rotation.mul_quat(Quat::from_rotation_y(FRAC_PI_2)),
             color: self.color,
+            fill: None,
             duration: self.duration,
         }
         .add_lines(lines);
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use super::{AddLines, Shape, ShapeHandle};
+
+pub struct Triangle {
+    pub points: [Vec3; 3],
+    pub color: Color,
+    /// Color the triangle is filled with, usually translucent. Only the outline is drawn if not
+    /// set.
+    pub fill: Option<Color>,
+    pub duration: f32,
+}
+
+impl Triangle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Triangle {
+    fn default() -> Self {
+        Self {
+            points: [Vec3::ZERO, Vec3::X, Vec3::Y],
+            color: Color::WHITE,
+            fill: None,
+            duration: 0.0,
+        }
+    }
+}
+
+impl From<Triangle> for Shape {
+    fn from(triangle: Triangle) -> Self {
+        Shape::Triangle(triangle)
+    }
+}
+
+impl AddLines for Triangle {
+    fn add_lines(&self, lines: &mut crate::DebugLines) {
+        let [a, b, c] = self.points;
+        lines.line_colored(a, b, self.duration, self.color);
+        lines.line_colored(b, c, self.duration, self.color);
+        lines.line_colored(c, a, self.duration, self.color);
+
+        if let Some(fill) = self.fill {
+            lines.triangle(a, b, c, self.duration, fill);
+        }
+    }
+}
+
+impl<'a> ShapeHandle<'a, Triangle> {
+    pub fn points(self, a: Vec3, b: Vec3, c: Vec3) -> Self {
+        if let Shape::Triangle(triangle) = &mut self.shapes.shapes[self.index] {
+            triangle.points = [a, b, c];
+        }
+        self
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        if let Shape::Triangle(triangle) = &mut self.shapes.shapes[self.index] {
+            triangle.color = color;
+        }
+        self
+    }
+
+    /// Fills the triangle with the given color, usually translucent.
+    pub fn fill(self, fill: Color) -> Self {
+        if let Shape::Triangle(triangle) = &mut self.shapes.shapes[self.index] {
+            triangle.fill = Some(fill);
+        }
+        self
+    }
+
+    pub fn duration(self, duration: f32) -> Self {
+        if let Shape::Triangle(triangle) = &mut self.shapes.shapes[self.index] {
+            triangle.duration = duration;
+        }
+        self
+    }
+}
@@ -0,0 +1,193 @@
+// A small generational slot map.
+//
+// Values are stored densely so iterating over them every frame is as cheap as iterating over a
+// `Vec`, and keys stay valid (and unique) while other values are added and removed.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct SlotKey {
+    index: u32,
+    generation: u32,
+}
+
+impl SlotKey {
+    // A key which never refers to a value.
+    pub(crate) const DANGLING: SlotKey = SlotKey {
+        index: u32::MAX,
+        generation: 0,
+    };
+}
+
+struct Slot {
+    // Incremented every time the value of the slot is removed.
+    generation: u32,
+    // Index of the value in `SlotMap::values`, if the slot is in use.
+    value: Option<u32>,
+}
+
+pub(crate) struct SlotMap<T> {
+    slots: Vec<Slot>,
+    free_slots: Vec<u32>,
+    values: Vec<T>,
+    // The slot of each value in `values`.
+    value_slots: Vec<u32>,
+}
+
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        Self {
+            slots: vec![],
+            free_slots: vec![],
+            values: vec![],
+            value_slots: vec![],
+        }
+    }
+}
+
+impl<T> SlotMap<T> {
+    pub(crate) fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub(crate) fn insert(&mut self, value: T) -> SlotKey {
+        let value_index = self.values.len() as u32;
+        let index = match self.free_slots.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: None,
+                });
+                self.slots.len() as u32 - 1
+            },
+        };
+
+        let slot = &mut self.slots[index as usize];
+        slot.value = Some(value_index);
+        self.values.push(value);
+        self.value_slots.push(index);
+
+        SlotKey {
+            index,
+            generation: slot.generation,
+        }
+    }
+
+    pub(crate) fn get(&self, key: SlotKey) -> Option<&T> {
+        self.value_index(key).map(|i| &self.values[i])
+    }
+
+    pub(crate) fn get_mut(&mut self, key: SlotKey) -> Option<&mut T> {
+        self.value_index(key).map(|i| &mut self.values[i])
+    }
+
+    pub(crate) fn remove(&mut self, key: SlotKey) -> Option<T> {
+        let value_index = self.value_index(key)?;
+        Some(self.remove_value(value_index))
+    }
+
+    // Keeps the values for which `keep` returns true.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&mut T) -> bool) {
+        let mut i = 0;
+        while i < self.values.len() {
+            if keep(&mut self.values[i]) {
+                i += 1;
+            } else {
+                self.remove_value(i);
+            }
+        }
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    fn value_index(&self, key: SlotKey) -> Option<usize> {
+        let slot = self.slots.get(key.index as usize)?;
+        if slot.generation != key.generation {
+            return None;
+        }
+        slot.value.map(|i| i as usize)
+    }
+
+    // Swap removes the value, and points the slot of the value which took its place to its new index.
+    fn remove_value(&mut self, value_index: usize) -> T {
+        let index = self.value_slots.swap_remove(value_index);
+        let slot = &mut self.slots[index as usize];
+        slot.value = None;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(index);
+
+        if let Some(&moved) = self.value_slots.get(value_index) {
+            self.slots[moved as usize].value = Some(value_index as u32);
+        }
+        self.values.swap_remove(value_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removed_key_is_stale() {
+        let mut map = SlotMap::default();
+        let a = map.insert("a");
+        let b = map.insert("b");
+        assert_eq!(map.remove(a), Some("a"));
+        assert_eq!(map.get(a), None);
+        assert_eq!(map.remove(a), None);
+        assert_eq!(map.get(b), Some(&"b"));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn reused_slot_bumps_generation() {
+        let mut map = SlotMap::default();
+        let a = map.insert("a");
+        map.remove(a);
+        let b = map.insert("b");
+        assert_eq!(b.index, a.index);
+        assert_ne!(b.generation, a.generation);
+        assert_eq!(map.get(a), None);
+        assert_eq!(map.get(b), Some(&"b"));
+    }
+
+    #[test]
+    fn retain_removes_last_value() {
+        let mut map = SlotMap::default();
+        let keys: Vec<_> = (0..3).map(|i| map.insert(i)).collect();
+        map.retain(|value| *value != 2);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(keys[0]), Some(&0));
+        assert_eq!(map.get(keys[1]), Some(&1));
+        assert_eq!(map.get(keys[2]), None);
+    }
+
+    #[test]
+    fn retain_removes_middle_value() {
+        let mut map = SlotMap::default();
+        let keys: Vec<_> = (0..4).map(|i| map.insert(i)).collect();
+        map.retain(|value| *value != 1);
+        assert_eq!(map.len(), 3);
+        // The last value was moved into the removed one's place, its key must still find it.
+        assert_eq!(map.get(keys[0]), Some(&0));
+        assert_eq!(map.get(keys[1]), None);
+        assert_eq!(map.get(keys[2]), Some(&2));
+        assert_eq!(map.get_mut(keys[3]), Some(&mut 3));
+        assert_eq!(map.remove(keys[3]), Some(3));
+        assert_eq!(map.iter().copied().collect::<Vec<_>>(), vec![0, 2]);
+    }
+
+    #[test]
+    fn dangling_key_never_resolves() {
+        let mut map = SlotMap::default();
+        assert_eq!(map.get(SlotKey::DANGLING), None);
+        for i in 0..8 {
+            map.insert(i);
+        }
+        assert_eq!(map.get(SlotKey::DANGLING), None);
+        assert_eq!(map.get_mut(SlotKey::DANGLING), None);
+        assert_eq!(map.remove(SlotKey::DANGLING), None);
+        assert_eq!(map.len(), 8);
+    }
+}
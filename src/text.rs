@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+
+/// The size of text drawn with [`DebugLines::text`](crate::DebugLines::text), the height of its
+/// capital letters.
+///
+/// A plain `f32` converts to a size in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextSize {
+    /// Size in pixels, the text is aligned with the screen and has the same size regardless of its
+    /// distance to the camera.
+    Pixels(f32),
+    /// Size in world units, the text faces the camera and gets smaller the further it is from it.
+    World(f32),
+}
+
+impl Default for TextSize {
+    fn default() -> Self {
+        TextSize::Pixels(16.0)
+    }
+}
+
+impl From<f32> for TextSize {
+    fn from(pixels: f32) -> Self {
+        TextSize::Pixels(pixels)
+    }
+}
+
+impl TextSize {
+    pub(crate) fn value(self) -> f32 {
+        match self {
+            TextSize::Pixels(size) | TextSize::World(size) => size,
+        }
+    }
+}
+
+// Glyphs are drawn on a grid 4 units wide. The baseline is at y = 2, lowercase letters are 4 units
+// high and capitals 6, with descenders going down to y = 0.
+const BASELINE: f32 = 2.0;
+const CAP_HEIGHT: f32 = 6.0;
+const ADVANCE: f32 = 5.0;
+const LINE_HEIGHT: f32 = 10.0;
+
+// Strokes of the printable ASCII characters, from ' ' to '~'. Each stroke is a polyline written
+// as a sequence of x and y digits, and strokes are separated by spaces.
+const GLYPHS: [&str; 95] = [
+    "",                             // ' '
+    "2824 2322",                    // '!'
+    "1817 3837",                    // '"'
+    "1713 3733 0646 0444",          // '#'
+    "470705454303 2822",            // '$'
+    "0248 0807 4342",               // '%'
+    "421618383604022244",           // '&'
+    "2827",                         // '''
+    "38272332",                     // '('
+    "18272312",                     // ')'
+    "2824 0745 0547",               // '*'
+    "2723 0545",                    // '+'
+    "232211",                       // ','
+    "0545",                         // '-'
+    "2322",                         // '.'
+    "0248",                         // '/'
+    "0242480802 0248",              // '0'
+    "1728 2822 1232",               // '1'
+    "084845050242",                 // '2'
+    "08484202 1545",                // '3'
+    "080545 4842",                  // '4'
+    "480805454202",                 // '5'
+    "480802424505",                 // '6'
+    "084822",                       // '7'
+    "0848420208 0545",              // '8'
+    "4248080545",                   // '9'
+    "2625 2322",                    // ':'
+    "2625 232211",                  // ';'
+    "470543",                       // '<'
+    "0444 0646",                    // '='
+    "074503",                       // '>'
+    "07183847462524 2322",          // '?'
+    "33361613334348080242",         // '@'
+    "0206284642 0545",              // 'A'
+    "02083847463505 3544433202",    // 'B'
+    "48080242",                     // 'C'
+    "02082846442202",               // 'D'
+    "48080242 0535",                // 'E'
+    "480802 0535",                  // 'F'
+    "480802424525",                 // 'G'
+    "0802 4842 0545",               // 'H'
+    "0848 2822 0242",               // 'I'
+    "48420204",                     // 'J'
+    "0802 480542",                  // 'K'
+    "080242",                       // 'L'
+    "0208254842",                   // 'M'
+    "02084248",                     // 'N'
+    "0242480802",                   // 'O'
+    "0208484505",                   // 'P'
+    "0242480802 2441",              // 'Q'
+    "0208484505 2542",              // 'R'
+    "480805454202",                 // 'S'
+    "0848 2822",                    // 'T'
+    "08024248",                     // 'U'
+    "082248",                       // 'V'
+    "0802254248",                   // 'W'
+    "0842 0248",                    // 'X'
+    "0825 4825 2522",               // 'Y'
+    "08480242",                     // 'Z'
+    "38181232",                     // '['
+    "0842",                         // '\'
+    "18383212",                     // ']'
+    "062846",                       // '^'
+    "0141",                         // '_'
+    "1827",                         // '`'
+    "064642020444",                 // 'a'
+    "0802424606",                   // 'b'
+    "46060242",                     // 'c'
+    "4842020646",                   // 'd'
+    "044446060242",                 // 'e'
+    "482822 1636",                  // 'f'
+    "420206464000",                 // 'g'
+    "0802 064642",                  // 'h'
+    "2622 2827",                    // 'i'
+    "262000 2827",                  // 'j'
+    "0802 460442",                  // 'k'
+    "2822",                         // 'l'
+    "02064642 2622",                // 'm'
+    "02064642",                     // 'n'
+    "0242460602",                   // 'o'
+    "0006464202",                   // 'p'
+    "4046060242",                   // 'q'
+    "0206 042646",                  // 'r'
+    "460604444202",                 // 's'
+    "282232 1636",                  // 't'
+    "06024246",                     // 'u'
+    "062246",                       // 'v'
+    "0602244246",                   // 'w'
+    "0642 0246",                    // 'x'
+    "060242 464000",                // 'y'
+    "06460242",                     // 'z'
+    "38282615242232",               // '{'
+    "2820",                         // '|'
+    "18282635242212",               // '}'
+    "05163546",                     // '~'
+];
+
+// The line segments of the text, with a cap height of 1. The first line of text starts at the
+// origin and sits on it, the next lines go down. Characters without a glyph are drawn as '?'.
+pub(crate) fn segments(text: &str) -> Vec<(Vec2, Vec2)> {
+    let mut segments = vec![];
+    for (row, line) in text.lines().enumerate() {
+        for (column, character) in line.chars().enumerate() {
+            let glyph = match character {
+                ' '..='~' => GLYPHS[character as usize - ' ' as usize],
+                _ => GLYPHS['?' as usize - ' ' as usize],
+            };
+            let origin = Vec2::new(column as f32 * ADVANCE, -BASELINE - row as f32 * LINE_HEIGHT);
+            for stroke in glyph.split(' ').filter(|stroke| !stroke.is_empty()) {
+                let points: Vec<Vec2> = stroke
+                    .as_bytes()
+                    .chunks(2)
+                    .map(|point| Vec2::new((point[0] - b'0') as f32, (point[1] - b'0') as f32))
+                    .map(|point| (origin + point) / CAP_HEIGHT)
+                    .collect();
+                segments.extend(points.windows(2).map(|pair| (pair[0], pair[1])));
+            }
+        }
+    }
+    segments
+}
@@ -0,0 +1,180 @@
+use bevy::{
+    prelude::*,
+    render::mesh::PrimitiveTopology,
+    sprite::Mesh2dHandle,
+    utils::{HashMap, HashSet},
+};
+
+use crate::{DebugLines, EntityVisibility, LineDimension};
+
+/// Draws the triangle edges of the mesh of the entity, following its [`GlobalTransform`].
+///
+/// Works with both 3d meshes (`Handle<Mesh>`) and 2d meshes ([`Mesh2dHandle`]). Unlike bevy's
+/// `WireframePlugin`, this doesn't need the `POLYGON_MODE_LINE` GPU feature. Edges shared by
+/// several triangles are only drawn once.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_prototype_debug_lines::*;
+///
+/// fn spawn_crate(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+///     commands.spawn((
+///         PbrBundle {
+///             mesh: meshes.add(shape::Cube::default().into()),
+///             ..default()
+///         },
+///         ShowWireframe { color: Color::GREEN },
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShowWireframe {
+    pub color: Color,
+}
+
+impl Default for ShowWireframe {
+    fn default() -> Self {
+        Self { color: Color::WHITE }
+    }
+}
+
+// Draws the mesh edges of the entities with `ShowWireframe`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn draw_wireframes(
+    mut lines: ResMut<DebugLines>,
+    meshes: Res<Assets<Mesh>>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+    // The edges of each mesh, so they are only computed again when the mesh changes.
+    mut edges: Local<HashMap<AssetId<Mesh>, Vec<(Vec3, Vec3)>>>,
+    visibility: EntityVisibility,
+    entities: Query<(
+        Entity,
+        &ShowWireframe,
+        Option<&Handle<Mesh>>,
+        Option<&Mesh2dHandle>,
+        &GlobalTransform,
+    )>,
+) {
+    for event in mesh_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            edges.remove(id);
+        }
+    }
+
+    let visible = entities.iter().filter(|(entity, ..)| visibility.is_visible(*entity));
+    for (_, wireframe, mesh_3d, mesh_2d, transform) in visible {
+        let Some((id, dimension)) = entity_mesh(mesh_3d, mesh_2d) else {
+            continue;
+        };
+        if !edges.contains_key(&id) {
+            // The mesh may not be loaded yet.
+            let Some(mesh) = meshes.get(id) else {
+                continue;
+            };
+            edges.insert(id, mesh_edges(mesh));
+        }
+
+        let mut lines = lines.dimension(dimension);
+        for &(start, end) in &edges[&id] {
+            lines.line_colored(
+                transform.transform_point(start),
+                transform.transform_point(end),
+                0.0,
+                wireframe.color,
+            );
+        }
+    }
+}
+
+// The mesh of an entity, and the kind of camera it is drawn for.
+pub(crate) fn entity_mesh(
+    mesh_3d: Option<&Handle<Mesh>>,
+    mesh_2d: Option<&Mesh2dHandle>,
+) -> Option<(AssetId<Mesh>, LineDimension)> {
+    match (mesh_3d, mesh_2d) {
+        (Some(handle), _) => Some((handle.id(), LineDimension::ThreeD)),
+        (None, Some(handle)) => Some((handle.0.id(), LineDimension::TwoD)),
+        (None, None) => None,
+    }
+}
+
+// Unique edges of the triangles of the mesh, empty if it isn't made of triangles.
+fn mesh_edges(mesh: &Mesh) -> Vec<(Vec3, Vec3)> {
+    let Some(positions) = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|positions| positions.as_float3())
+    else {
+        return vec![];
+    };
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..positions.len()).collect(),
+    };
+    let triangles: Vec<&[usize]> = match mesh.primitive_topology() {
+        PrimitiveTopology::TriangleList => indices.chunks_exact(3).collect(),
+        PrimitiveTopology::TriangleStrip => indices.windows(3).collect(),
+        _ => return vec![],
+    };
+
+    let mut seen = HashSet::new();
+    let mut edges = vec![];
+    for triangle in triangles {
+        for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+            let (Some(&start), Some(&end)) = (positions.get(triangle[a]), positions.get(triangle[b])) else {
+                continue;
+            };
+            // Edges are compared by position rather than index, since meshes often have several
+            // vertices at the same position with different normals or uvs.
+            let start_bits = start.map(f32::to_bits);
+            let end_bits = end.map(f32::to_bits);
+            let key = if start_bits <= end_bits {
+                (start_bits, end_bits)
+            } else {
+                (end_bits, start_bits)
+            };
+            if key.0 != key.1 && seen.insert(key) {
+                edges.push((Vec3::from(start), Vec3::from(end)));
+            }
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::mesh::Indices;
+
+    use super::*;
+
+    fn quad(indexed: bool) -> Mesh {
+        let corners = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]];
+        let indices = [0, 1, 2, 0, 2, 3];
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        if indexed {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, corners.to_vec());
+            mesh.set_indices(Some(Indices::U32(indices.to_vec())));
+        } else {
+            let positions: Vec<[f32; 3]> = indices.iter().map(|&i| corners[i as usize]).collect();
+            mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        }
+        mesh
+    }
+
+    #[test]
+    fn quad_shares_its_diagonal() {
+        assert_eq!(mesh_edges(&quad(true)).len(), 5);
+        assert_eq!(mesh_edges(&quad(false)).len(), 5);
+    }
+
+    #[test]
+    fn cube_edges() {
+        // 12 edges and a diagonal for each of the 6 faces, although each face has its own vertices.
+        assert_eq!(mesh_edges(&Mesh::from(shape::Cube::new(1.0))).len(), 18);
+    }
+
+    #[test]
+    fn lines_have_no_edges() {
+        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+        assert!(mesh_edges(&mesh).is_empty());
+    }
+}